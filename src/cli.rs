@@ -0,0 +1,798 @@
+pub mod seed_tree;
+pub mod subcommand;
+
+extern crate base16ct;
+extern crate binary_diff;
+extern crate blake3;
+extern crate clap;
+extern crate log;
+extern crate regex;
+extern crate sha1;
+extern crate sha2;
+extern crate xxhash_rust;
+
+use crate::seed_tree::hash_algorithm::HashAlgorithm;
+use crate::seed_tree::hash_cache::FileHashCache;
+use crate::seed_tree::loader::input_format::InputFormat;
+use crate::seed_tree::loader::{load_inputs, LoadOptions};
+use crate::seed_tree::parser::afl::{link_afl_input_corpus, AFLExtensions};
+use crate::seed_tree::plot_options::plot_option::PlotOption;
+use crate::seed_tree::plot_options::PlotOptions;
+use crate::seed_tree::sort_order::SortOrder;
+use crate::seed_tree::util::available_jobs;
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::afl::aurora::aurora;
+use crate::subcommand::afl::campaign::campaign;
+use crate::subcommand::afl::filter::filter;
+use crate::subcommand::afl::plot::plot;
+use crate::subcommand::afl::preds::preds;
+use crate::subcommand::afl::seeds::seeds;
+use crate::subcommand::afl::shell::shell;
+use crate::subcommand::common::children::children;
+use crate::subcommand::common::compare::compare;
+use crate::subcommand::common::deriv::deriv;
+use crate::subcommand::common::dups::dups;
+use crate::subcommand::common::gain::gain;
+use crate::subcommand::common::leaves::leaves;
+use crate::subcommand::common::max_rank::max_rank;
+use crate::subcommand::common::merge::merge;
+use crate::subcommand::common::missing::missing;
+use crate::subcommand::common::nodes::nodes;
+use crate::subcommand::common::origin::origin;
+use crate::subcommand::common::pred::pred;
+use crate::subcommand::common::roots::roots;
+use crate::subcommand::common::save::save;
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::libfuzzer::cycles::cycles;
+use crate::subcommand::libfuzzer::lint::lint;
+use crate::subcommand::libfuzzer::stats::stats;
+use crate::subcommand::util::corpus::link_corpus;
+use crate::subcommand::util::exit::{exit_with_error, require_path_exists};
+use crate::subcommand::util::fuzzer_log::apply_libfuzzer_logs;
+use crate::subcommand::util::hash_algorithm::hash_algorithm_of;
+use crate::subcommand::util::query::apply_query;
+use crate::subcommand::util::sort_order::sort_order_of;
+use clap::{App, Arg, SubCommand};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+// NOTE: Also run by former binaries seed-tree-analyzer-afl and seed-tree-analyzer-libfuzzer
+pub fn main() {
+    env_logger::init();
+
+    let matches = App::new("seed-tree-analyzer")
+        .version("1.0")
+        .author("Nao Tomori (@K_atc)")
+        .about("A Tool to interact with seed trees of AFL, libFuzzer and snapshots saved by this tool.\nFormat of INPUT is detected automatically.")
+        .arg(
+            Arg::with_name("INPUT")
+                .help("AFL's output directories, libFuzzer's mutation graph files, LibAFL's corpus directories, honggfuzz's workspaces, a snapshot file or a DOT file. Seed tree in DOT format is read from stdin if omitted")
+                .required(false)
+                .index(1)
+                .multiple(true),
+        )
+        .arg(
+            Arg::with_name("FORMAT")
+                .long("format")
+                .possible_values(InputFormat::NAMES)
+                .help("Format of INPUT. Default is detected from INPUT")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("CRASH_INPUT_DIR")
+                .long("crash")
+                .help("Treat CRASH_INPUT_DIR as a directory contains crash inputs. These are highlighted in the seed tree. Default is a directory named \"crashes\" treated as crash input directory")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("ENABLE_AURORA")
+                .long("aurora")
+                .help("Enable [AUORA] extension. [AUORA] is https://github.com/RUB-SysSec/aurora")
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("CACHE_FILE")
+                .long("cache")
+                .help("Cache file hashes in CACHE_FILE keyed by path, size and mtime. Unchanged files are not rehashed on next run")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("LOG_FILE")
+                .long("log")
+                .help("Apply libFuzzer's log (stderr) LOG_FILE: nodes found at NEW lines get coverage stats (see gain), and reported crash-/leak-/timeout-/oom- artifacts are attached to their base units as crashed nodes. Can be given multiple times")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .arg(
+            Arg::with_name("CORPUS_DIR")
+                .long("corpus")
+                .help("Link nodes to files in CORPUS_DIR (searched recursively) by hash of contents (see --hash-algorithm). Renamed files and crash-/leak-/timeout- artifacts are also found. Can be given multiple times")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .arg(
+            Arg::with_name("INPUT_CORPUS_DIR")
+                .long("input-corpus")
+                .help("Resolve initial seeds named by orig: of AFL's inputs to files in INPUT_CORPUS_DIR (i.e. -i of afl-fuzz) and mark them as initial seeds (see seeds). Can be given multiple times")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .arg(
+            Arg::with_name("JOBS")
+                .long("jobs")
+                .short("j")
+                .help("Number of threads to parse and hash input files. Default is number of CPUs")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("HASH_ALGORITHM")
+                .long("hash-algorithm")
+                .possible_values(HashAlgorithm::NAMES)
+                .help("Algorithm to hash contents of input files. Default is sha1, which matches names of libFuzzer's inputs")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("SORT")
+                .long("sort")
+                .global(true)
+                .possible_values(SortOrder::NAMES)
+                .help("Order of nodes in listings and DOT graphs: name, id (order of declaration), discovery (discovery time) or topo (parents first). Default is name")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("QUERY")
+                .long("query")
+                .short("q")
+                .global(true)
+                .help("Select nodes by QUERY before running subcommand, e.g. 'crashed && depth > 5 && op ~ \"splice\"'. See README for syntax")
+                .takes_value(true),
+        )
+        .subcommand(
+            SubCommand::with_name("parse")
+                .about("Load INPUT(s) and output seed tree in dot format."),
+        )
+        .subcommand(
+            SubCommand::with_name("cycles")
+                .about("List cycles and re-parenting conflicts. These edges are kept as weak edges.")
+                .arg(
+                    Arg::with_name("all")
+                        .long("all")
+                        .help("Also list duplicate edges and weak edges declared by input")
+                        .takes_value(false),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("gain")
+                .about("Report coverage (cov) and features (ft) gained by each node in lineage of NODE_NAME (see --log).")
+                .arg(
+                    Arg::with_name("NODE_NAME")
+                        .required(true)
+                        .index(1),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("missing")
+                .about("List nodes whose seed files are not found on disk (see --corpus).")
+                .arg(
+                    Arg::with_name("SEEDS_DIR")
+                        .long("seeds")
+                        .help("Also look up seed files in SEEDS_DIR by node name")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("NODE_NAME")
+                        .help("List only missing nodes in lineage of NODE_NAME")
+                        .index(1),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("lint")
+                .about("Report problems of a libFuzzer's mutation graph file with line and column, e.g. duplicate nodes, self-loops and truncated lines.")
+                .arg(
+                    Arg::with_name("summary")
+                        .long("summary")
+                        .help("Print only counts of problems")
+                        .takes_value(false),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("stats")
+                .about("Count nodes, edges and mutators by streaming libFuzzer's mutation graph files. Works on huge files in bounded memory.")
+                .arg(
+                    Arg::with_name("TOP")
+                        .long("top")
+                        .help("Number of most used mutators to list. Default is 10")
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("plot")
+                .about("Plot and save seed tree as DOT, PNG, SVG.\nThis command requires graphviz.")
+                .arg(
+                    Arg::with_name("DOT_FILE")
+                        .help("Path of dot file to be saved")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("ID")
+                        .help("Highlight edges from root to ID")
+                        .index(2),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("save")
+                .about("Save seed tree to SNAPSHOT_FILE in compact binary format.\nSaved seed tree can be loaded as INPUT.")
+                .arg(
+                    Arg::with_name("SNAPSHOT_FILE")
+                        .help("Path of snapshot file to be saved")
+                        .required(true)
+                        .index(1),
+                ),
+        )
+        .subcommand(SubCommand::with_name("roots").about("List root nodes."))
+        .subcommand(SubCommand::with_name("leaves").about("List leaf nodes."))
+        .subcommand(
+            SubCommand::with_name("nodes")
+                .alias("ls")
+                .about("List nodes.")
+                .arg(
+                    Arg::with_name("meta")
+                        .long("meta")
+                        .takes_value(false)
+                        .help("Print metadata of nodes")
+                )
+                .arg(
+                    Arg::with_name("file")
+                        .long("file")
+                        .takes_value(false)
+                        .help("Print file path of nodes. This option cannot be enabled with --meta")
+                )
+        )
+        .subcommand(
+            SubCommand::with_name("maxrank")
+                .about("List nodes at maximum rank.")
+                .arg(
+                    Arg::with_name("meta")
+                        .long("meta")
+                        .takes_value(false)
+                        .help("Print metadata of nodes")
+                )
+                .arg(
+                    Arg::with_name("file")
+                        .long("file")
+                        .takes_value(false)
+                        .help("Print file path of nodes. This option cannot be enabled with --meta")
+                )
+        )
+        .subcommand(
+            SubCommand::with_name("dups")
+                .about("List inputs with same contents found multiple times (e.g. rediscovered via different lineages or imported by sync), which reveals wasted fuzzing effort."),
+        )
+        .subcommand(
+            SubCommand::with_name("children")
+                .about("List children of node ID")
+                .arg(
+                    Arg::with_name("ID")
+                        .help("Node ID")
+                        .required(true)
+                        .index(1),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("compare")
+                .about("Compare seed tree of INPUT(s) (A) with one of OTHER(s) (B) matching nodes by contents: unique nodes and edges, shared crashes reached via different lineages, and nodes by depth and edges by operator side by side.")
+                .arg(
+                    Arg::with_name("OTHER")
+                        .help("Inputs of seed tree to be compared in any format of INPUT")
+                        .required(true)
+                        .multiple(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("list")
+                        .long("list")
+                        .help("List nodes and edges unique to each seed tree")
+                        .takes_value(false),
+                )
+                .arg(
+                    Arg::with_name("DOT_FILE")
+                        .long("plot")
+                        .help("Save combined seed tree to DOT_FILE and render it as SVG. Edges only in B are green and edges only in A are red. Nodes only in A are prefixed with \"left:\"")
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("merge")
+                .about("Merge seed trees of INPUT(s) and each SOURCE (e.g. libFuzzer's mutation graph and AFL's output directory on same target) unifying nodes with same contents, and save merged seed tree to SNAPSHOT_FILE.\nSources which each node comes from are recorded in the snapshot.")
+                .arg(
+                    Arg::with_name("SNAPSHOT_FILE")
+                        .help("Path of snapshot file to be saved")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("SOURCE")
+                        .help("Inputs of seed tree to be merged in any format of INPUT. Each SOURCE is loaded as a separate seed tree")
+                        .required(true)
+                        .multiple(true)
+                        .index(2),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("aurora")
+                .about("Summarize crash exploration of Aurora (see --aurora): numbers of crash inputs, non-crash inputs, queue entries and seeds, or crashing and non-crashing siblings along lineage of ID.")
+                .arg(
+                    Arg::with_name("ID")
+                        .help("Count crashing and non-crashing siblings of each node in lineage of ID")
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("EXPORT_DIR")
+                        .long("export")
+                        .help("Copy crash and non-crash (nc-<id>) inputs to EXPORT_DIR/crashes and EXPORT_DIR/non_crashes, the layout Aurora's tracing stage expects. Queue entries and seeds are not exported")
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("seeds")
+                .about("Rank initial seeds given by --input-corpus by numbers of descendants and crashes they produced."),
+        )
+        .subcommand(
+            SubCommand::with_name("campaign")
+                .about("Summarize AFL campaign from fuzzer_stats and plot_data: stats of each instance, execs per discovered path by depth and nodes discovered during coverage plateaus.")
+                .arg(
+                    Arg::with_name("OUTPUT_DIR")
+                        .help("AFL's output directories of instances or of multi-instance run. Default is around input directories")
+                        .multiple(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("PLATEAU")
+                        .long("plateau")
+                        .help("Minimum seconds without coverage growth to be a plateau. Default is 600")
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("filter")
+                .about("Filter seed tree using commandline options and print it as DOT graph")
+                .arg(
+                    Arg::with_name("PRED_ID")
+                        .long("pred")
+                        .takes_value(true)
+                        .help("Pick predecessors of PRED_ID"),
+                )
+                .arg(
+                    Arg::with_name("leaves")
+                        .long("leaves")
+                        .takes_value(false)
+                        .help("Pick leaves of picked nodes"),
+                )
+                .arg(
+                    Arg::with_name("meta")
+                        .long("meta")
+                        .takes_value(false)
+                        .help("Print metadata of nodes")
+                )
+                .arg(
+                    Arg::with_name("file")
+                        .long("file")
+                        .takes_value(false)
+                        .help("Print file path of nodes. This option cannot be enabled with --meta")
+                )
+        )
+        .subcommand(
+            SubCommand::with_name("preds")
+                .alias("pred")
+                .about("List predecessors of given ID and ID")
+                .arg(
+                    Arg::with_name("ID")
+                        .required(true)
+                        .index(1)
+                )
+                .arg(
+                    Arg::with_name("meta")
+                        .long("meta")
+                        .takes_value(false)
+                        .help("Print metadata of matched nodes")
+                )
+                .arg(
+                    Arg::with_name("file")
+                        .long("file")
+                        .takes_value(false)
+                        .help("Print file path of matched nodes. This option cannot be enabled with --meta")
+                )
+                .arg(
+                    Arg::with_name("hash")
+                        .long("hash")
+                        .takes_value(false)
+                        .help("ID is meant to be file hash or its unambiguous prefix (e.g. 3a5443)")
+                )
+                .arg(
+                    Arg::with_name("export")
+                        .long("export")
+                        .takes_value(true)
+                        .help("Copy input files to given directory")
+                )
+                .arg(
+                    Arg::with_name("jobs")
+                        .long("jobs")
+                        .help("Show files (jobs) which edges to each predecessor come from")
+                        .takes_value(false),
+                )
+                .arg(
+                    Arg::with_name("SEEDS_DIR_TO_DIFF")
+                        .long("diff")
+                        .value_name("SEEDS_DIR")
+                        .help("Diff seed files along predecessors. Seed files are located by file paths of nodes, then in SEEDS_DIR")
+                        .takes_value(true)
+                        .min_values(0)
+                        .max_values(1),
+                )
+                .arg(
+                    Arg::with_name("SEEDS_DIR_TO_EXISTS")
+                        .long("exists")
+                        .value_name("SEEDS_DIR")
+                        .help("List predecessors whose seed files exist. Seed files are located by file paths of nodes, then in SEEDS_DIR")
+                        .takes_value(true)
+                        .min_values(0)
+                        .max_values(1),
+                )
+        )
+        .subcommand(
+            SubCommand::with_name("deriv")
+                .about("Analyze derivation of OFFSET of node ID")
+                .arg(
+                    Arg::with_name("ID")
+                        .help("Node ID (i.e. seed file name)")
+                        .required(true)
+                        .takes_value(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("OFFSET")
+                        .help("Offset of ID")
+                        .required(true)
+                        .takes_value(true)
+                        .index(2),
+                )
+                .arg(
+                    Arg::with_name("SEEDS_DIR")
+                        .long("seeds")
+                        .help("Seed files location used when file path of node is unknown (e.g. libFuzzer's corpus)")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("plot")
+                        .long("plot")
+                        .help("Output highlighted seed tree in dot format")
+                        .takes_value(false),
+                )
+        )
+        .subcommand(
+            SubCommand::with_name("origin")
+                .about("Find origin seeds on each offset of node ID")
+                .arg(
+                    Arg::with_name("ID")
+                        .help("Node ID (i.e. seed file name)")
+                        .required(true)
+                        .takes_value(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("MINIMIZED_CRASH_INPUT")
+                        .help("If MINIMIZED_CRASH_INPUT is specified, offsets deleted by MINIMIZED_CRASH_INPUT are ignored during origin analysis")
+                        .required(false)
+                        .takes_value(true)
+                        .index(2),
+                )
+                .arg(
+                    Arg::with_name("SEEDS_DIR")
+                        .long("seeds")
+                        .help("Seed files location used when file path of node is unknown (e.g. libFuzzer's corpus)")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("plot")
+                        .long("plot")
+                        .help("Output notated seed tree in dot format")
+                        .takes_value(false),
+                )
+        )
+        .subcommand(
+            SubCommand::with_name("shell")
+                .about("Load seed tree once and run commands interactively (preds, children, roots, diff, origin, plot, stats, ...)")
+                .arg(
+                    Arg::with_name("SEEDS_DIR")
+                        .long("seeds")
+                        .help("Default seed files location of commands in the shell used when file path of node is unknown")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("HISTORY_FILE")
+                        .long("history")
+                        .help("Load and save command history from/to HISTORY_FILE")
+                        .takes_value(true),
+                )
+        )
+        .get_matches();
+
+    if matches.subcommand_name().is_none() {
+        exit_with_error(
+            "Failed to run",
+            SubcommandError::MissingArgument("SUBCOMMAND"),
+        );
+    }
+
+    let inputs: Vec<&Path> = match matches.values_of("INPUT") {
+        Some(inputs) => inputs.map(|path| Path::new(path)).collect(),
+        None => Vec::new(),
+    };
+    log::info!("inputs = {:?}", inputs);
+
+    let crash_inputs_dir = match matches.value_of("CRASH_INPUT_DIR") {
+        Some(crash_inputs_dir) => {
+            Some(require_path_exists(Path::new(crash_inputs_dir)).to_path_buf())
+        }
+        None => None,
+    };
+    let options = LoadOptions {
+        format: match matches.value_of("FORMAT") {
+            // NOTE: Value is validated by clap with `InputFormat::NAMES`
+            Some(format) => InputFormat::from_name(format),
+            None => None,
+        },
+        afl_extensions: AFLExtensions {
+            aurora: matches.is_present("ENABLE_AURORA"),
+            crash_inputs_dir,
+        },
+        jobs: match matches.value_of("JOBS") {
+            Some(jobs) => jobs
+                .parse::<usize>()
+                .unwrap_or_else(|why| exit_with_error("JOBS must be a number", why)),
+            None => available_jobs(),
+        },
+    };
+    log::info!("Options: {:?}", options);
+
+    // NOTE: lint parses the file by itself since the file may be rejected by strict parser
+    if let Some(matches) = matches.subcommand_matches("lint") {
+        let input = match inputs.as_slice() {
+            [input] => *input,
+            _ => exit_with_error(
+                "Failed to run",
+                SubcommandError::InvalidArgument(
+                    "INPUT",
+                    "lint takes one mutation graph file".to_string(),
+                ),
+            ),
+        };
+        require_mutation_graph_file(input, &options, "lint");
+        if let Err(why) = lint(matches, input) {
+            exit_with_error("Failed to run", why);
+        }
+        return;
+    }
+    // NOTE: stats streams each file without building whole graph
+    if let Some(matches) = matches.subcommand_matches("stats") {
+        if inputs.is_empty() {
+            exit_with_error(
+                "Failed to run",
+                SubcommandError::InvalidArgument(
+                    "INPUT",
+                    "stats takes mutation graph files".to_string(),
+                ),
+            );
+        }
+        for input in inputs.iter() {
+            require_mutation_graph_file(input, &options, "stats");
+            if let Err(why) = stats(matches, input) {
+                exit_with_error("Failed to run", why);
+            }
+        }
+        return;
+    }
+
+    let cache_file = matches.value_of("CACHE_FILE");
+    let mut cache = match cache_file {
+        Some(cache_file) => FileHashCache::load(cache_file, hash_algorithm_of(&matches))
+            .unwrap_or_else(|why| exit_with_error("Failed to load hash cache", why)),
+        None => FileHashCache::with_algorithm(hash_algorithm_of(&matches)),
+    };
+
+    let started = Instant::now();
+    let mut graph = match load_inputs(&inputs, &options, &mut cache, std::io::stdin()) {
+        Ok(graph) => graph,
+        Err(why) => exit_with_error("Failed to load inputs", why),
+    };
+    log::info!(
+        "Loaded {} nodes in {:?} (approx. {} bytes)",
+        graph.node_count(),
+        started.elapsed(),
+        graph.approximate_memory_usage()
+    );
+    // NOTE: Seed tree to be compared is loaded with same options and hash cache
+    let other = match matches.subcommand_matches("compare") {
+        Some(matches) => {
+            // NOTE: OTHER is required by clap
+            let others: Vec<&Path> = matches.values_of("OTHER").unwrap().map(Path::new).collect();
+            match load_inputs(&others, &options, &mut cache, std::io::empty()) {
+                Ok(other) => Some(other),
+                Err(why) => exit_with_error("Failed to load inputs to be compared", why),
+            }
+        }
+        None => None,
+    };
+    // NOTE: Each source to be merged is loaded separately to record which source nodes come from
+    let mut sources = Vec::new();
+    if let Some(matches) = matches.subcommand_matches("merge") {
+        // NOTE: SOURCE is required by clap
+        for source in matches.values_of("SOURCE").unwrap() {
+            match load_inputs(&[Path::new(source)], &options, &mut cache, std::io::empty()) {
+                Ok(graph) => sources.push((source.to_string(), graph)),
+                Err(why) => exit_with_error("Failed to load inputs to be merged", why),
+            }
+        }
+    }
+    if let Some(log_files) = matches.values_of("LOG_FILE") {
+        let log_files: Vec<&str> = log_files.collect();
+        if let Err(why) = apply_libfuzzer_logs(&log_files, &mut graph) {
+            exit_with_error("Failed to parse libFuzzer log", why);
+        }
+    }
+    if let Some(corpus_dirs) = matches.values_of("CORPUS_DIR") {
+        let corpus_dirs: Vec<&str> = corpus_dirs.collect();
+        if let Err(why) = link_corpus(&corpus_dirs, &mut graph, &mut cache, options.jobs) {
+            exit_with_error("Failed to index corpus", why);
+        }
+    }
+    if let Some(input_corpus_dirs) = matches.values_of("INPUT_CORPUS_DIR") {
+        let input_corpus_dirs: Vec<&str> = input_corpus_dirs.collect();
+        match link_afl_input_corpus(&input_corpus_dirs, &mut graph, &mut cache, options.jobs) {
+            Ok(linked) => log::info!("Resolved {} initial seeds", linked),
+            Err(why) => exit_with_error("Failed to resolve initial seeds", why),
+        }
+    }
+    if let Some(cache_file) = cache_file {
+        log::info!(
+            "Hash cache: hits={}, misses={}",
+            cache.hits(),
+            cache.misses()
+        );
+        if let Err(why) = cache.save(cache_file) {
+            exit_with_error("Failed to save hash cache", why);
+        }
+    }
+
+    let graph = match apply_query(&matches, graph) {
+        Ok(graph) => graph,
+        Err(why) => exit_with_error("Failed to apply query", why),
+    };
+
+    let other = match other.map(|other| apply_query(&matches, other)) {
+        Some(Ok(other)) => Some(other),
+        Some(Err(why)) => exit_with_error("Failed to apply query", why),
+        None => None,
+    };
+
+    let sources: Vec<(String, MutationGraph)> = sources
+        .into_iter()
+        .map(|(source, graph)| match apply_query(&matches, graph) {
+            Ok(graph) => (source, graph),
+            Err(why) => exit_with_error("Failed to apply query", why),
+        })
+        .collect();
+
+    let sort_order = sort_order_of(&matches);
+    let base_plot_option = vec![
+        PlotOption::HighlightCrashInput,
+        PlotOption::SortBy(sort_order),
+    ];
+
+    let result = if let Some(_matches) = matches.subcommand_matches("parse") {
+        let plot_options = PlotOptions {
+            sort_order,
+            ..PlotOptions::none()
+        };
+        graph
+            .dot_graph(plot_options)
+            .map(|dot| println!("{}", dot))
+            .map_err(SubcommandError::from)
+    } else if let Some(matches) = matches.subcommand_matches("plot") {
+        plot(matches, &graph, base_plot_option.as_slice())
+    } else if let Some(matches) = matches.subcommand_matches("save") {
+        save(matches, &graph)
+    } else if let Some(matches) = matches.subcommand_matches("cycles") {
+        cycles(matches, &graph)
+    } else if let Some(matches) = matches.subcommand_matches("gain") {
+        gain(matches, &graph)
+    } else if let Some(matches) = matches.subcommand_matches("missing") {
+        missing(matches, &graph)
+    } else if let Some(matches) = matches.subcommand_matches("roots") {
+        roots(matches, &graph)
+    } else if let Some(matches) = matches.subcommand_matches("leaves") {
+        leaves(matches, &graph)
+    } else if let Some(matches) = matches.subcommand_matches("nodes") {
+        nodes(matches, &graph)
+    } else if let Some(matches) = matches.subcommand_matches("maxrank") {
+        max_rank(matches, &graph)
+    } else if let Some(matches) = matches.subcommand_matches("children") {
+        children(matches, &graph)
+    } else if let Some(matches) = matches.subcommand_matches("dups") {
+        dups(matches, &graph)
+    } else if let Some(matches) = matches.subcommand_matches("compare") {
+        compare(
+            matches,
+            &graph,
+            other.as_ref().expect("Loaded with compare"),
+        )
+    } else if let Some(matches) = matches.subcommand_matches("merge") {
+        let input = match inputs.as_slice() {
+            [] => String::from("stdin"),
+            inputs => inputs
+                .iter()
+                .map(|input| input.display().to_string())
+                .collect::<Vec<String>>()
+                .join(","),
+        };
+        // NOTE: Seed tree of INPUT(s) is merged first, so its node names are kept
+        let mut all_sources = vec![(input, graph)];
+        all_sources.extend(sources);
+        merge(matches, &all_sources)
+    } else if let Some(matches) = matches.subcommand_matches("aurora") {
+        aurora(matches, &graph)
+    } else if let Some(matches) = matches.subcommand_matches("seeds") {
+        seeds(matches, &graph)
+    } else if let Some(matches) = matches.subcommand_matches("campaign") {
+        campaign(matches, &graph, &inputs)
+    } else if let Some(matches) = matches.subcommand_matches("filter") {
+        filter(matches, &graph, base_plot_option.as_slice())
+    } else if let Some(matches) = matches.subcommand_matches("preds") {
+        // NOTE: Seed contents and jobs are analyzed only when --diff, --exists or --jobs is given
+        if matches.is_present("SEEDS_DIR_TO_DIFF")
+            || matches.is_present("SEEDS_DIR_TO_EXISTS")
+            || matches.is_present("jobs")
+        {
+            pred(matches, &graph)
+        } else {
+            preds(matches, &graph)
+        }
+    } else if let Some(matches) = matches.subcommand_matches("deriv") {
+        deriv(matches, graph)
+    } else if let Some(matches) = matches.subcommand_matches("origin") {
+        let additional_file = match matches.value_of("MINIMIZED_CRASH_INPUT") {
+            Some(additional_file) => Some(PathBuf::from(additional_file)),
+            None => None,
+        };
+        origin(matches, &graph, additional_file)
+    } else if let Some(matches) = matches.subcommand_matches("shell") {
+        shell(matches, &graph, base_plot_option.as_slice())
+    } else {
+        Err(SubcommandError::MissingArgument("SUBCOMMAND"))
+    };
+    if let Err(why) = result {
+        exit_with_error("Failed to run", why);
+    }
+}
+
+// Exits unless `input` is libFuzzer's mutation graph file, which `subcommand` reads by itself
+fn require_mutation_graph_file(input: &Path, options: &LoadOptions, subcommand: &str) {
+    let format = match options.format {
+        Some(format) => format,
+        None => InputFormat::detect(input)
+            .unwrap_or_else(|why| exit_with_error("Failed to load inputs", why)),
+    };
+    if format != InputFormat::LibFuzzerMutationGraph {
+        exit_with_error(
+            "Failed to run",
+            SubcommandError::InvalidArgument(
+                "INPUT",
+                format!("{} does not support {} input", subcommand, format.name()),
+            ),
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use super::corpus::name_hash_of;
+use super::file_hash::FileHash;
+use super::mutation_graph_edge::MutationGraphEdge;
+use super::mutation_graph_node::MutationGraphNode;
+use super::node_name::NodeName;
+use super::plot_options::PlotOptions;
+use super::result::Result;
+use super::sort_order::SortOrder;
+use super::MutationGraph;
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Prefix of names of nodes only in left graph in combined graph of `SeedTreeComparison::dot_graph()`
+pub const LEFT_ONLY_PREFIX: &str = "left:";
+
+/// Key to match nodes across seed trees
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ContentKey<'a> {
+    Hash(FileHash),
+    /// Name of node whose contents are unknown (e.g. `orig:` names of AFL's initial seeds)
+    Name(&'a NodeName),
+}
+
+/// Returns key to match nodes across seed trees: hash of contents if known, otherwise SHA-1 which node name
+/// consists of (i.e. libFuzzer's nodes without corpus) or node name
+pub fn content_key_of(node: &MutationGraphNode) -> ContentKey<'_> {
+    if !node.hash.is_empty() {
+        ContentKey::Hash(node.hash.clone())
+    } else if let Some(hash) = name_hash_of(&node.name) {
+        ContentKey::Hash(hash)
+    } else {
+        ContentKey::Name(&node.name)
+    }
+}
+
+/// Crash found in both seed trees whose predecessors have different contents
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DivergentCrash<'a> {
+    pub left: Vec<&'a NodeName>,
+    pub right: Vec<&'a NodeName>,
+    /// Number of leading nodes of lineages sharing contents
+    pub common: usize,
+}
+
+/// Differences of two seed trees (e.g. of campaigns with different configurations) matching nodes by contents.
+/// Names of nodes are not compared since they are meaningless across campaigns (e.g. AFL's ids).
+#[derive(Debug)]
+pub struct SeedTreeComparison<'a> {
+    left: &'a MutationGraph,
+    right: &'a MutationGraph,
+    left_name: HashMap<ContentKey<'a>, &'a NodeName>,
+    right_name: HashMap<ContentKey<'a>, &'a NodeName>,
+    /// Nodes whose contents are not in the other seed tree
+    pub left_only_nodes: Vec<&'a NodeName>,
+    pub right_only_nodes: Vec<&'a NodeName>,
+    /// Edges whose pair of contents is not in the other seed tree. Labels are not compared.
+    pub left_only_edges: Vec<MutationGraphEdge>,
+    pub right_only_edges: Vec<MutationGraphEdge>,
+    /// Crashes in both seed trees reached via different lineages, in order of left crashes
+    pub divergent_crashes: Vec<DivergentCrash<'a>>,
+}
+
+impl<'a> SeedTreeComparison<'a> {
+    pub fn new(left: &'a MutationGraph, right: &'a MutationGraph) -> Result<Self> {
+        let left_name = names_by_content_of(left);
+        let right_name = names_by_content_of(right);
+
+        let left_only_nodes = only_nodes_of(left, &right_name);
+        let right_only_nodes = only_nodes_of(right, &left_name);
+
+        let left_only_edges = only_edges_of(left, right);
+        let right_only_edges = only_edges_of(right, left);
+
+        let mut divergent_crashes = Vec::new();
+        for node in left.nodes().filter(|node| node.crashed) {
+            let other = match right_name.get(&content_key_of(node)) {
+                Some(other) if right.get_node(other).is_some_and(|v| v.crashed) => *other,
+                _ => continue,
+            };
+            let left_lineage = left.self_and_its_predecessors_of(&node.name)?;
+            let right_lineage = right.self_and_its_predecessors_of(other)?;
+            let left_keys = content_keys_of(left, &left_lineage);
+            let right_keys = content_keys_of(right, &right_lineage);
+            if left_keys != right_keys {
+                let common = left_keys
+                    .iter()
+                    .zip(right_keys.iter())
+                    .take_while(|(l, r)| l == r)
+                    .count();
+                divergent_crashes.push(DivergentCrash {
+                    left: left_lineage,
+                    right: right_lineage,
+                    common,
+                });
+            }
+        }
+
+        Ok(Self {
+            left,
+            right,
+            left_name,
+            right_name,
+            left_only_nodes,
+            right_only_nodes,
+            left_only_edges,
+            right_only_edges,
+            divergent_crashes,
+        })
+    }
+
+    /// Dumps combined seed tree in dot format: right seed tree with nodes and edges only in left one.
+    /// Edges only in right one are green (i.e. added), and edges only in left one are red (i.e. removed).
+    /// Nodes only in left one are named with `LEFT_ONLY_PREFIX`.
+    pub fn dot_graph(&self, sort_order: SortOrder) -> Result<String> {
+        let mut graph = self.right.clone();
+        let mut removed = HashSet::new();
+        for name in self.left_only_nodes.iter() {
+            let node = self.left.get_node(name).expect("Node of left graph");
+            graph.add_node(&MutationGraphNode {
+                name: format!("{}{}", LEFT_ONLY_PREFIX, name),
+                ..node.clone()
+            });
+        }
+        for edge in self.left_only_edges.iter() {
+            let edge = MutationGraphEdge {
+                parent: self.combined_name_of(&edge.parent),
+                child: self.combined_name_of(&edge.child),
+                label: edge.label.clone(),
+            };
+            graph.add_edge(&edge);
+            removed.insert(edge);
+        }
+
+        graph.dot_graph(PlotOptions {
+            highlight_edge_with_green: self.right_only_edges.iter().cloned().collect(),
+            highlight_edge_with_red: removed,
+            highlight_crash_input: true,
+            sort_order,
+            ..PlotOptions::none()
+        })
+    }
+
+    // Name of node of left graph in combined graph
+    fn combined_name_of(&self, name: &NodeName) -> NodeName {
+        let key = match self.left.get_node(name) {
+            Some(node) => content_key_of(node),
+            None => ContentKey::Name(name),
+        };
+        match self.right_name.get(&key) {
+            Some(name) => (*name).clone(),
+            None => format!("{}{}", LEFT_ONLY_PREFIX, name),
+        }
+    }
+
+    /// Name of node in left seed tree having same contents as `name` of right one
+    pub fn left_name_of(&self, name: &NodeName) -> Option<&'a NodeName> {
+        let node = self.right.get_node(name)?;
+        self.left_name.get(&content_key_of(node)).cloned()
+    }
+}
+
+// NOTE: First node in order of id is kept for nodes with same contents
+fn names_by_content_of(graph: &MutationGraph) -> HashMap<ContentKey<'_>, &NodeName> {
+    let mut res = HashMap::new();
+    for node in graph.nodes() {
+        res.entry(content_key_of(node)).or_insert(&node.name);
+    }
+    res
+}
+
+fn only_nodes_of<'a>(
+    graph: &'a MutationGraph,
+    other: &HashMap<ContentKey<'_>, &NodeName>,
+) -> Vec<&'a NodeName> {
+    graph
+        .nodes()
+        .filter(|node| !other.contains_key(&content_key_of(node)))
+        .map(|node| &node.name)
+        .collect()
+}
+
+fn content_keys_of<'a>(graph: &'a MutationGraph, names: &[&NodeName]) -> Vec<ContentKey<'a>> {
+    names
+        .iter()
+        .filter_map(|name| graph.get_node(name))
+        .map(content_key_of)
+        .collect()
+}
+
+fn only_edges_of(graph: &MutationGraph, other: &MutationGraph) -> Vec<MutationGraphEdge> {
+    // NOTE: Both ends of edges are nodes of graph
+    fn key_of<'a>(graph: &'a MutationGraph, name: &NodeName) -> Option<ContentKey<'a>> {
+        graph.get_node(name).map(content_key_of)
+    }
+    let other_edges: HashSet<_> = other
+        .edges()
+        .map(|edge| (key_of(other, &edge.parent), key_of(other, &edge.child)))
+        .collect();
+    graph
+        .edges()
+        .filter(|edge| {
+            !other_edges.contains(&(key_of(graph, &edge.parent), key_of(graph, &edge.child)))
+        })
+        .collect()
+}
+
+/// Counts nodes of `graph` at each depth (i.e. rank)
+pub fn depth_histogram_of(graph: &MutationGraph) -> BTreeMap<usize, usize> {
+    let mut depth: HashMap<&NodeName, usize> = HashMap::new();
+    let mut res = BTreeMap::new();
+    // NOTE: Parents precede their children in topological order
+    for node in graph.sorted_nodes(SortOrder::Topological) {
+        let d = match graph.parent_of(&node.name) {
+            Some(parent) => depth.get(parent).map_or(0, |v| v + 1),
+            None => 0,
+        };
+        depth.insert(&node.name, d);
+        *res.entry(d).or_insert(0) += 1;
+    }
+    res
+}
+
+/// Counts edges of `graph` by their labels (i.e. mutation operators)
+pub fn operator_histogram_of(graph: &MutationGraph) -> BTreeMap<String, usize> {
+    let mut res = BTreeMap::new();
+    for edge in graph.edges() {
+        *res.entry(edge.label).or_insert(0) += 1;
+    }
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::compare::{depth_histogram_of, SeedTreeComparison, LEFT_ONLY_PREFIX};
+    use crate::seed_tree::sort_order::SortOrder;
+    use crate::seed_tree::test_util::graph_of;
+
+    #[test]
+    fn test_compare_seed_trees() {
+        //   left:  seed -> 1(a) -> 2(b) -> crash(x)
+        //   right: seed -> 5(a) -> 6(c) -> crash(x), 5(a) -> 7(d)
+        let left = graph_of(
+            &[
+                ("seed", "", false),
+                ("1", "a", false),
+                ("2", "b", false),
+                ("crash-1", "x", true),
+            ],
+            &[("seed", "1"), ("1", "2"), ("2", "crash-1")],
+        );
+        let right = graph_of(
+            &[
+                ("seed", "", false),
+                ("5", "a", false),
+                ("6", "c", false),
+                ("7", "d", false),
+                ("crash-3", "x", true),
+            ],
+            &[("seed", "5"), ("5", "6"), ("6", "crash-3"), ("5", "7")],
+        );
+
+        let comparison = SeedTreeComparison::new(&left, &right).unwrap();
+        assert_eq!(comparison.left_only_nodes, vec!["2"]);
+        assert_eq!(comparison.right_only_nodes, vec!["6", "7"]);
+        assert_eq!(comparison.left_only_edges.len(), 2);
+        assert_eq!(comparison.right_only_edges.len(), 3);
+        assert_eq!(comparison.divergent_crashes.len(), 1);
+        assert_eq!(comparison.divergent_crashes[0].common, 2);
+        assert_eq!(
+            comparison.divergent_crashes[0].right,
+            vec!["seed", "5", "6", "crash-3"]
+        );
+        assert_eq!(
+            comparison.left_name_of(&String::from("5")),
+            Some(&String::from("1"))
+        );
+
+        let dot = comparison.dot_graph(SortOrder::Name).unwrap();
+        assert!(dot.contains(&format!(
+            "\"5\" -> \"{}2\" [label=\"havoc\", splines=curved, color=\"red\"];",
+            LEFT_ONLY_PREFIX
+        )));
+        assert!(
+            dot.contains("\"5\" -> \"7\" [label=\"havoc\", splines=curved, color=\"darkgreen\"];")
+        );
+
+        assert_eq!(
+            depth_histogram_of(&right).into_iter().collect::<Vec<_>>(),
+            vec![(0, 1), (1, 1), (2, 2), (3, 1)]
+        );
+    }
+}
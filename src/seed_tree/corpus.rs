@@ -0,0 +1,155 @@
+use super::file_hash::FileHash;
+use super::hash_algorithm::HashAlgorithm;
+use super::hash_cache::FileHashCache;
+use super::node_name::NodeName;
+use super::parser::error::ParseError;
+use super::parser::result::Result;
+use super::MutationGraph;
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Prefixes of file names given by libFuzzer to inputs found problems (e.g. `crash-<SHA-1>`)
+pub const ARTIFACT_PREFIXES: &[&str] = &["crash-", "leak-", "timeout-", "oom-", "slow-unit-"];
+
+/// Index of files in corpus directories keyed by hashes of their contents with algorithm of the cache.
+/// Files are found by contents, so renamed files and artifacts with prefixes are also linked to nodes.
+#[derive(Debug, Clone)]
+pub struct CorpusIndex {
+    file: HashMap<FileHash, PathBuf>,
+    scanned: usize,
+}
+
+impl CorpusIndex {
+    /// Scans `directories` recursively and hashes files on `jobs` threads. Hidden files are skipped.
+    pub fn scan<P: AsRef<Path>>(
+        directories: &[P],
+        cache: &mut FileHashCache,
+        jobs: usize,
+    ) -> Result<Self> {
+        let mut files = Vec::new();
+        for directory in directories.iter() {
+            let directory = directory.as_ref();
+            if !directory.is_dir() {
+                return Err(ParseError::UnexpectedFilePath(directory.to_path_buf()));
+            }
+            list_files(directory, &mut files)?;
+        }
+        // NOTE: First path in order of name is kept for files with same contents
+        files.sort();
+
+        let mut file = HashMap::new();
+        for (path, hash) in files.iter().zip(cache.calc_file_hashes(&files, jobs)) {
+            let hash = hash.map_err(|why| ParseError::from(why).in_file(path))?;
+            file.entry(hash).or_insert_with(|| path.clone());
+        }
+        Ok(Self {
+            file,
+            scanned: files.len(),
+        })
+    }
+
+    /// Number of scanned files including ones with same contents
+    pub fn scanned(&self) -> usize {
+        self.scanned
+    }
+
+    /// Number of distinct contents
+    pub fn len(&self) -> usize {
+        self.file.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.file.is_empty()
+    }
+
+    pub fn path_of(&self, hash: &FileHash) -> Option<&Path> {
+        self.file.get(hash).map(|v| v.as_path())
+    }
+
+    /// Fills file and hash of nodes found in this index. Returns number of linked nodes.
+    /// Nodes are looked up by their hash if known, otherwise by their names (i.e. SHA-1 names of libFuzzer).
+    /// Names match only if files are indexed by SHA-1.
+    pub fn link(&self, graph: &mut MutationGraph) -> usize {
+        let linked: Vec<_> = graph
+            .nodes()
+            .filter_map(|node| {
+                let hash = if node.hash.is_empty() {
+                    name_hash_of(&node.name)?
+                } else {
+                    node.hash.clone()
+                };
+                let path = self.path_of(&hash)?;
+                let mut node = node.clone();
+                node.file = path.to_path_buf();
+                node.hash = hash;
+                Some(node)
+            })
+            .collect();
+        for node in linked.iter() {
+            graph.add_node(node);
+        }
+        linked.len()
+    }
+}
+
+// Returns SHA-1 which `name` consists of, dropping prefix of artifacts
+pub(crate) fn name_hash_of(name: &NodeName) -> Option<FileHash> {
+    let name = ARTIFACT_PREFIXES
+        .iter()
+        .find_map(|prefix| name.strip_prefix(prefix))
+        .unwrap_or(name);
+    FileHash::from_hex(HashAlgorithm::Sha1, name)
+}
+
+// Lists files under `directory` recursively skipping hidden files
+pub(crate) fn list_files(directory: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    let entries = directory
+        .read_dir()
+        .map_err(|why| ParseError::from(why).in_file(directory))?;
+    for entry in entries {
+        let entry = entry.map_err(|why| ParseError::from(why).in_file(directory))?;
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        // NOTE: Symbolic links to directories are not followed to avoid loops
+        match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() => list_files(&path, files)?,
+            _ if path.is_file() => files.push(path),
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::corpus::CorpusIndex;
+    use crate::seed_tree::file_hash::FileHash;
+    use crate::seed_tree::hash_algorithm::HashAlgorithm;
+    use crate::seed_tree::hash_cache::FileHashCache;
+    use crate::seed_tree::node_name::NodeName;
+    use crate::seed_tree::parser::libfuzzer::parse_libfuzzer_mutation_graph_file;
+    use std::path::Path;
+
+    #[test]
+    fn test_corpus_index() {
+        let corpus = Path::new("test/sample/seeds/fuzzer-test-suite-openssl-1.0.1f");
+        let mut graph = parse_libfuzzer_mutation_graph_file(
+            "test/sample/mutation_graph_file/fuzzer-test-suite-openssl-1.0.1f.dot",
+        )
+        .unwrap();
+        let index = CorpusIndex::scan(&[corpus], &mut FileHashCache::new(), 2).unwrap();
+        assert!(!index.is_empty() && index.len() <= index.scanned());
+
+        let linked = index.link(&mut graph);
+        assert!(linked > 0 && linked <= index.len());
+        let name = NodeName::from("0dafd00a785bd3d2cb36722c29f0dd23497833b0");
+        let node = graph.get_node(&name).unwrap();
+        assert_eq!(node.file, corpus.join(&name));
+        let hash = FileHash::from_hex(HashAlgorithm::Sha1, &name).unwrap();
+        assert_eq!(node.hash, hash);
+        assert_eq!(graph.lookup_by_file_hash(&hash), Ok(&name));
+    }
+}
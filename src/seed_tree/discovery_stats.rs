@@ -0,0 +1,19 @@
+/// Status of libFuzzer when a node was discovered, taken from its `NEW` line in fuzzer's log
+///
+/// ```text
+/// #1234 NEW    cov: 812 ft: 2011 corp: 45/3Kb lim: 4 exec/s: 0 rss: 30Mb L: 3/3 MS: 3 ChangeBit-CopyPart-InsertByte-
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DiscoveryStats {
+    /// Number of executions before discovery (`#N`)
+    pub execs: u64,
+    /// Covered edges (`cov:`) and features (`ft:`) after discovery
+    pub cov: u64,
+    pub ft: u64,
+    /// Increase of `cov` and `ft` from previous status line
+    pub cov_gain: u64,
+    pub ft_gain: u64,
+    /// Number of units and their total size in bytes in corpus after discovery (`corp:`)
+    pub corpus_units: u64,
+    pub corpus_bytes: u64,
+}
@@ -1,11 +1,54 @@
 use crate::seed_tree::node_name::NodeName;
 
 use super::file_hash::FileHash;
+use std::fmt;
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum MutationGraphError {
     NodeNotExists(NodeName),
     FileHashNotExists(FileHash),
-    FmtError(std::fmt::Error),
+    FileHashPrefixNotExists(String),
+    AmbiguousFileHash(String, Vec<FileHash>),
+    CycleDetected(NodeName),
+    FmtError(fmt::Error),
     // IoError, // NOTE: std::io::Error does not satisfies PartialEq
 }
+
+impl fmt::Display for MutationGraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NodeNotExists(name) => write!(f, "node {:?} does not exist", name),
+            Self::FileHashNotExists(hash) => write!(f, "no node has file hash {:?}", hash),
+            Self::FileHashPrefixNotExists(prefix) => {
+                write!(f, "no node has file hash starting with {:?}", prefix)
+            }
+            Self::AmbiguousFileHash(prefix, hashes) => write!(
+                f,
+                "file hash prefix {:?} is ambiguous: {}",
+                prefix,
+                hashes
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Self::CycleDetected(name) => write!(f, "cycle detected at node {:?}", name),
+            Self::FmtError(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for MutationGraphError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::FmtError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<fmt::Error> for MutationGraphError {
+    fn from(error: fmt::Error) -> Self {
+        Self::FmtError(error)
+    }
+}
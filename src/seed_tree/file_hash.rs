@@ -1,2 +1,204 @@
-pub type FileHash = String;
-// pub type FileHash = sha1::Sha1; // => the trait `Hash` is not implemented for `CoreWrapper<Sha1Core>`
+use super::hash_algorithm::HashAlgorithm;
+
+use sha1::{Digest, Sha1};
+use sha2::Sha256;
+use std::fmt;
+use std::io;
+use xxhash_rust::xxh3::Xxh3;
+
+/// Length of longest digest of `HashAlgorithm`
+pub const MAX_DIGEST_LEN: usize = 32;
+
+/// Digest of contents of an input with algorithm which calculated it.
+/// Default value is empty, which means contents are unknown (e.g. nodes of libFuzzer's mutation graph without corpus).
+#[derive(Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FileHash {
+    algorithm: Option<HashAlgorithm>,
+    // NOTE: Bytes after digest length of algorithm are zeros
+    digest: [u8; MAX_DIGEST_LEN],
+}
+
+impl FileHash {
+    /// Hashes `data` in memory
+    pub fn of(algorithm: HashAlgorithm, data: &[u8]) -> Self {
+        let mut hasher = FileHasher::new(algorithm);
+        hasher.update(data);
+        hasher.finalize()
+    }
+
+    /// Returns `None` unless length of `bytes` is same as digest of `algorithm`
+    pub fn from_bytes(algorithm: HashAlgorithm, bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != algorithm.digest_len() {
+            return None;
+        }
+        let mut digest = [0u8; MAX_DIGEST_LEN];
+        digest[..bytes.len()].copy_from_slice(bytes);
+        Some(Self {
+            algorithm: Some(algorithm),
+            digest,
+        })
+    }
+
+    /// Parses digest of `algorithm` in hex (e.g. SHA-1 names of libFuzzer's inputs). Case is ignored.
+    pub fn from_hex(algorithm: HashAlgorithm, hex: &str) -> Option<Self> {
+        let mut bytes = [0u8; MAX_DIGEST_LEN];
+        match base16ct::mixed::decode(hex, &mut bytes) {
+            Ok(bytes) => Self::from_bytes(algorithm, bytes),
+            Err(_) => None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.algorithm.is_none()
+    }
+
+    pub fn algorithm(&self) -> Option<HashAlgorithm> {
+        self.algorithm
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        let len = self.algorithm.map_or(0, |v| v.digest_len());
+        &self.digest[..len]
+    }
+
+    /// Whether hex of this digest starts with `prefix` (e.g. `3a5443`). Case is ignored.
+    pub fn starts_with_hex(&self, prefix: &str) -> bool {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        let bytes = self.as_bytes();
+        prefix.len() <= bytes.len() * 2
+            && prefix.bytes().enumerate().all(|(i, c)| {
+                let nibble = if i % 2 == 0 {
+                    bytes[i / 2] >> 4
+                } else {
+                    bytes[i / 2] & 0x0f
+                };
+                HEX[nibble as usize] == c.to_ascii_lowercase()
+            })
+    }
+}
+
+impl fmt::Display for FileHash {
+    /// Lower case hex of digest. Empty if contents are unknown.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.as_bytes() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for FileHash {
+    // NOTE: Printed as hex string to keep output of `--meta` readable
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.to_string())
+    }
+}
+
+enum State {
+    Sha1(Sha1),
+    Sha256(Sha256),
+    Blake3(Box<blake3::Hasher>),
+    Xxh3(Box<Xxh3>),
+}
+
+/// Streaming hasher of `HashAlgorithm`, which file contents are copied into by `io::copy()`
+pub struct FileHasher {
+    algorithm: HashAlgorithm,
+    state: State,
+}
+
+impl FileHasher {
+    pub fn new(algorithm: HashAlgorithm) -> Self {
+        let state = match algorithm {
+            HashAlgorithm::Sha1 => State::Sha1(Sha1::new()),
+            HashAlgorithm::Sha256 => State::Sha256(Sha256::new()),
+            HashAlgorithm::Blake3 => State::Blake3(Box::new(blake3::Hasher::new())),
+            HashAlgorithm::Xxh3 => State::Xxh3(Box::new(Xxh3::new())),
+        };
+        Self { algorithm, state }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        match self.state {
+            State::Sha1(ref mut hasher) => Digest::update(hasher, data),
+            State::Sha256(ref mut hasher) => Digest::update(hasher, data),
+            State::Blake3(ref mut hasher) => {
+                hasher.update(data);
+            }
+            State::Xxh3(ref mut hasher) => hasher.update(data),
+        }
+    }
+
+    pub fn finalize(self) -> FileHash {
+        let digest = match self.state {
+            State::Sha1(hasher) => hasher.finalize().to_vec(),
+            State::Sha256(hasher) => hasher.finalize().to_vec(),
+            State::Blake3(hasher) => hasher.finalize().as_bytes().to_vec(),
+            State::Xxh3(hasher) => hasher.digest128().to_be_bytes().to_vec(),
+        };
+        FileHash::from_bytes(self.algorithm, &digest).expect("Digest of algorithm")
+    }
+}
+
+impl io::Write for FileHasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::file_hash::FileHash;
+    use crate::seed_tree::hash_algorithm::HashAlgorithm;
+
+    #[test]
+    fn test_file_hash() {
+        let sha1 = FileHash::of(HashAlgorithm::Sha1, b"");
+        assert_eq!(sha1.to_string(), "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+        assert_eq!(
+            FileHash::from_hex(
+                HashAlgorithm::Sha1,
+                "DA39A3EE5E6B4B0D3255BFEF95601890AFD80709"
+            ),
+            Some(sha1.clone())
+        );
+        assert_eq!(FileHash::from_hex(HashAlgorithm::Sha1, "da39a3"), None);
+        assert_eq!(
+            FileHash::from_hex(HashAlgorithm::Sha256, &sha1.to_string()),
+            None
+        );
+        assert!(sha1.starts_with_hex("da39A3e"));
+        assert!(!sha1.starts_with_hex("da39a4"));
+        assert!(!sha1.starts_with_hex(&format!("{}0", sha1)));
+
+        assert_eq!(
+            FileHash::of(HashAlgorithm::Sha256, b"").to_string(),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            FileHash::of(HashAlgorithm::Blake3, b"").to_string(),
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+        );
+        for algorithm in HashAlgorithm::NAMES
+            .iter()
+            .filter_map(|v| HashAlgorithm::from_name(v))
+        {
+            let hash = FileHash::of(algorithm, b"seed");
+            assert_eq!(hash.algorithm(), Some(algorithm));
+            assert_eq!(hash.as_bytes().len(), algorithm.digest_len());
+        }
+
+        // Digests of different algorithms are never same
+        assert_ne!(
+            FileHash::of(HashAlgorithm::Sha256, b"seed"),
+            FileHash::of(HashAlgorithm::Blake3, b"seed")
+        );
+        assert!(FileHash::default().is_empty());
+        assert_eq!(FileHash::default().to_string(), "");
+    }
+}
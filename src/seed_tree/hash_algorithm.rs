@@ -0,0 +1,44 @@
+/// Algorithm to hash contents of inputs
+// NOTE: SHA-1 is default to link libFuzzer's nodes named by SHA-1 of inputs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HashAlgorithm {
+    /// SHA-1, which libFuzzer names inputs with
+    #[default]
+    Sha1,
+    Sha256,
+    Blake3,
+    /// 128-bit XXH3, which is fast but not cryptographic
+    Xxh3,
+}
+
+impl HashAlgorithm {
+    pub const NAMES: &'static [&'static str] = &["sha1", "sha256", "blake3", "xxh3"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "sha1" => Some(Self::Sha1),
+            "sha256" => Some(Self::Sha256),
+            "blake3" => Some(Self::Blake3),
+            "xxh3" => Some(Self::Xxh3),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Sha1 => "sha1",
+            Self::Sha256 => "sha256",
+            Self::Blake3 => "blake3",
+            Self::Xxh3 => "xxh3",
+        }
+    }
+
+    /// Length of digest in bytes
+    pub fn digest_len(&self) -> usize {
+        match self {
+            Self::Sha1 => 20,
+            Self::Sha256 | Self::Blake3 => 32,
+            Self::Xxh3 => 16,
+        }
+    }
+}
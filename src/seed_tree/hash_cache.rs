@@ -0,0 +1,444 @@
+use super::file_hash::FileHash;
+use super::hash_algorithm::HashAlgorithm;
+use super::node_name::NodeName;
+use super::snapshot::codec::{Decoder, Encoder};
+use super::snapshot::error::SnapshotError;
+use super::snapshot::result::Result;
+use super::util::{calc_file_hash, parallel_map};
+
+use std::collections::HashMap;
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+pub const MAGIC: &[u8; 8] = b"SEEDHASH";
+pub const VERSION: u32 = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FileStamp {
+    size: u64,
+    mtime_secs: u64,
+    mtime_nanos: u32,
+}
+
+impl FileStamp {
+    fn of<P: AsRef<Path>>(path: P) -> io::Result<Option<Self>> {
+        let metadata = fs::metadata(path)?;
+        // NOTE: Some file systems do not support mtime. Files on them are never cached.
+        let mtime = match metadata.modified() {
+            Ok(mtime) => match mtime.duration_since(UNIX_EPOCH) {
+                Ok(mtime) => mtime,
+                Err(_) => return Ok(None),
+            },
+            Err(_) => return Ok(None),
+        };
+        Ok(Some(Self {
+            size: metadata.len(),
+            mtime_secs: mtime.as_secs(),
+            mtime_nanos: mtime.subsec_nanos(),
+        }))
+    }
+}
+
+/// Node parsed from a file name, with name and operation of its parent input if any
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedNode {
+    pub name: NodeName,
+    pub crashed: bool,
+    pub discovered: Option<u64>,
+    pub parent: Option<(NodeName, String)>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    stamp: FileStamp,
+    hash: FileHash,
+    // Parsed node and key of the parser which parsed it
+    node: Option<(String, CachedNode)>,
+    // Whether the file is looked up in this run. Not saved.
+    used: bool,
+}
+
+/// Caches file hashes and parsed nodes keyed by path, size and mtime to avoid rehashing
+/// and reparsing unchanged files. Files are hashed with `HashAlgorithm` of the cache (SHA-1 by default).
+#[derive(Debug, Clone, Default)]
+pub struct FileHashCache {
+    algorithm: HashAlgorithm,
+    entries: HashMap<PathBuf, Entry>,
+    hits: usize,
+    misses: usize,
+}
+
+impl FileHashCache {
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn with_algorithm(algorithm: HashAlgorithm) -> Self {
+        Self {
+            algorithm,
+            ..Default::default()
+        }
+    }
+
+    /// Loads cache of `algorithm` from `path`. Returns empty cache if `path` does not exist.
+    /// Caches of older versions or other algorithms are discarded, since they are rebuilt on saving.
+    pub fn load<P: AsRef<Path>>(path: P, algorithm: HashAlgorithm) -> Result<Self> {
+        let mut cache = Self::with_algorithm(algorithm);
+        if !path.as_ref().exists() {
+            return Ok(cache);
+        }
+
+        let mut decoder = Decoder::new(BufReader::new(File::open(path.as_ref())?));
+        let mut magic = [0u8; 8];
+        decoder.read_raw(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(SnapshotError::InvalidMagic);
+        }
+        let version = decoder.read_u32()?;
+        if version != VERSION {
+            log::warn!(
+                "Discarded hash cache {:?} of version {}",
+                path.as_ref(),
+                version
+            );
+            return Ok(cache);
+        }
+        let name = decoder.read_string()?;
+        if name != algorithm.name() {
+            log::warn!(
+                "Discarded hash cache {:?} of algorithm {}",
+                path.as_ref(),
+                name
+            );
+            return Ok(cache);
+        }
+
+        let mut digest = vec![0u8; algorithm.digest_len()];
+        for _ in 0..decoder.read_u64()? {
+            let path = decoder.read_path()?;
+            let stamp = FileStamp {
+                size: decoder.read_u64()?,
+                mtime_secs: decoder.read_u64()?,
+                mtime_nanos: decoder.read_u32()?,
+            };
+            decoder.read_raw(&mut digest)?;
+            let hash = FileHash::from_bytes(algorithm, &digest).expect("Digest of algorithm");
+            let node = if decoder.read_bool()? {
+                let parser = decoder.read_string()?;
+                let name = decoder.read_string()?;
+                let crashed = decoder.read_bool()?;
+                let discovered = if decoder.read_bool()? {
+                    Some(decoder.read_u64()?)
+                } else {
+                    None
+                };
+                let parent = if decoder.read_bool()? {
+                    Some((decoder.read_string()?, decoder.read_string()?))
+                } else {
+                    None
+                };
+                Some((
+                    parser,
+                    CachedNode {
+                        name,
+                        crashed,
+                        discovered,
+                        parent,
+                    },
+                ))
+            } else {
+                None
+            };
+            cache.entries.insert(
+                path,
+                Entry {
+                    stamp,
+                    hash,
+                    node,
+                    used: false,
+                },
+            );
+        }
+        Ok(cache)
+    }
+
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    /// Saves cache to `path`. Entries of files which are not looked up in this run and no longer exist are evicted.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut encoder = Encoder::new(BufWriter::new(File::create(path)?));
+        encoder.write_raw(MAGIC)?;
+        encoder.write_u32(VERSION)?;
+        encoder.write_str(self.algorithm.name())?;
+
+        let mut entries: Vec<(&PathBuf, &Entry)> = self
+            .entries
+            .iter()
+            .filter(|(path, entry)| entry.used || path.exists())
+            .collect();
+        if entries.len() < self.entries.len() {
+            log::info!(
+                "Evicted {} entries of deleted files from hash cache",
+                self.entries.len() - entries.len()
+            );
+        }
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        encoder.write_u64(entries.len() as u64)?;
+        for (path, entry) in entries {
+            encoder.write_path(path)?;
+            encoder.write_u64(entry.stamp.size)?;
+            encoder.write_u64(entry.stamp.mtime_secs)?;
+            encoder.write_u32(entry.stamp.mtime_nanos)?;
+            encoder.write_raw(entry.hash.as_bytes())?;
+            encoder.write_bool(entry.node.is_some())?;
+            if let Some((parser, node)) = entry.node.as_ref() {
+                encoder.write_str(parser)?;
+                encoder.write_str(&node.name)?;
+                encoder.write_bool(node.crashed)?;
+                encoder.write_bool(node.discovered.is_some())?;
+                if let Some(discovered) = node.discovered {
+                    encoder.write_u64(discovered)?;
+                }
+                encoder.write_bool(node.parent.is_some())?;
+                if let Some((parent, label)) = node.parent.as_ref() {
+                    encoder.write_str(parent)?;
+                    encoder.write_str(label)?;
+                }
+            }
+        }
+        encoder.flush()
+    }
+
+    /// Returns cached nodes and hashes of `paths` parsed by `parser` if the files are not modified since cached.
+    /// Files are checked by `jobs` worker threads.
+    pub fn lookup_nodes<P: AsRef<Path> + Sync>(
+        &mut self,
+        paths: &[P],
+        parser: &str,
+        jobs: usize,
+    ) -> Vec<Option<(CachedNode, FileHash)>> {
+        let results = {
+            let entries = &self.entries;
+            parallel_map(paths, jobs, |path| -> Option<(CachedNode, FileHash)> {
+                let entry = entries.get(path.as_ref())?;
+                let (key, node) = entry.node.as_ref()?;
+                if key != parser || FileStamp::of(path.as_ref()).ok()?? != entry.stamp {
+                    return None;
+                }
+                Some((node.clone(), entry.hash.clone()))
+            })
+        };
+
+        for (path, result) in paths.iter().zip(results.iter()) {
+            if result.is_some() {
+                self.hits += 1;
+                if let Some(entry) = self.entries.get_mut(path.as_ref()) {
+                    entry.used = true;
+                }
+            }
+        }
+        results
+    }
+
+    /// Caches `node` parsed by `parser` from `path`. The file must be hashed by `calc_file_hashes()` before.
+    pub fn insert_node<P: AsRef<Path>>(&mut self, path: P, parser: &str, node: CachedNode) {
+        if let Some(entry) = self.entries.get_mut(path.as_ref()) {
+            entry.node = Some((parser.to_string(), node));
+        }
+    }
+
+    /// Returns cached hash of `path` if the file is not modified since cached, otherwise calculates it.
+    pub fn calc_file_hash<P: AsRef<Path> + Sync>(&mut self, path: P) -> io::Result<FileHash> {
+        self.calc_file_hashes(&[path], 1)
+            .pop()
+            .expect("Result of given path")
+    }
+
+    /// Parallel version of `calc_file_hash()`. Files not in cache are hashed by `jobs` worker threads.
+    pub fn calc_file_hashes<P: AsRef<Path> + Sync>(
+        &mut self,
+        paths: &[P],
+        jobs: usize,
+    ) -> Vec<io::Result<FileHash>> {
+        let results = {
+            let entries = &self.entries;
+            let algorithm = self.algorithm;
+            parallel_map(
+                paths,
+                jobs,
+                |path| -> io::Result<(Option<FileStamp>, FileHash, bool)> {
+                    let stamp = FileStamp::of(path.as_ref())?;
+                    if let Some(ref stamp) = stamp {
+                        if let Some(entry) = entries.get(path.as_ref()) {
+                            if &entry.stamp == stamp {
+                                return Ok((None, entry.hash.clone(), true));
+                            }
+                        }
+                    }
+                    Ok((stamp, calc_file_hash(path.as_ref(), algorithm)?, false))
+                },
+            )
+        };
+
+        paths
+            .iter()
+            .zip(results)
+            .map(|(path, result)| {
+                let (stamp, hash, hit) = result?;
+                if hit {
+                    self.hits += 1;
+                } else {
+                    self.misses += 1;
+                }
+                match stamp {
+                    Some(stamp) => {
+                        self.entries.insert(
+                            path.as_ref().to_path_buf(),
+                            Entry {
+                                stamp,
+                                hash: hash.clone(),
+                                node: None,
+                                used: true,
+                            },
+                        );
+                    }
+                    None => {
+                        if let Some(entry) = self.entries.get_mut(path.as_ref()) {
+                            entry.used = true;
+                        }
+                    }
+                }
+                Ok(hash)
+            })
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CachedNode, FileHashCache};
+    use crate::seed_tree::file_hash::FileHash;
+    use crate::seed_tree::hash_algorithm::HashAlgorithm;
+
+    fn sha1(hex: &str) -> FileHash {
+        FileHash::from_hex(HashAlgorithm::Sha1, hex).unwrap()
+    }
+
+    #[test]
+    fn test_file_hash_cache() {
+        let seed = "test/sample/seeds/fuzzer-test-suite-openssl-1.0.1f/0dafd00a785bd3d2cb36722c29f0dd23497833b0";
+        let cache_file = std::env::temp_dir().join(format!(
+            "seed-tree-analyzer-test-hash-cache-{}",
+            std::process::id()
+        ));
+
+        let mut cache = FileHashCache::new();
+        assert_eq!(
+            cache.calc_file_hash(seed).unwrap(),
+            sha1("0dafd00a785bd3d2cb36722c29f0dd23497833b0")
+        );
+        assert_eq!((cache.hits(), cache.misses()), (0, 1));
+        assert!(cache.save(&cache_file).is_ok());
+
+        // Cache of other algorithm is not used
+        let cache = FileHashCache::load(&cache_file, HashAlgorithm::Blake3).unwrap();
+        assert!(cache.is_empty());
+
+        let mut cache = FileHashCache::load(&cache_file, HashAlgorithm::Sha1).unwrap();
+        let _ = std::fs::remove_file(&cache_file);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(
+            cache.calc_file_hash(seed).unwrap(),
+            sha1("0dafd00a785bd3d2cb36722c29f0dd23497833b0")
+        );
+        assert_eq!((cache.hits(), cache.misses()), (1, 0));
+
+        let hashes = cache.calc_file_hashes(&[seed, "test/sample/seeds/fuzzer-test-suite-openssl-1.0.1f/ff5fbe1e41fd8101c12337ff5eb5a664b2197823"], 2);
+        assert_eq!(
+            hashes
+                .into_iter()
+                .map(|v| v.unwrap())
+                .collect::<Vec<FileHash>>(),
+            vec![
+                sha1("0dafd00a785bd3d2cb36722c29f0dd23497833b0"),
+                sha1("ff5fbe1e41fd8101c12337ff5eb5a664b2197823")
+            ]
+        );
+        assert_eq!((cache.hits(), cache.misses()), (2, 1));
+
+        let mut cache = FileHashCache::with_algorithm(HashAlgorithm::Xxh3);
+        let hash = cache.calc_file_hash(seed).unwrap();
+        assert_eq!(hash.algorithm(), Some(HashAlgorithm::Xxh3));
+        assert_eq!(
+            hash,
+            FileHash::of(HashAlgorithm::Xxh3, &std::fs::read(seed).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_file_hash_cache_nodes() {
+        let dir = std::env::temp_dir().join(format!(
+            "seed-tree-analyzer-test-hash-cache-nodes-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("id:000001,src:000000,op:havoc");
+        let cache_file = dir.join("cache");
+        std::fs::write(&input, "input").unwrap();
+        let node = CachedNode {
+            name: "000001".to_string(),
+            crashed: false,
+            discovered: Some(42),
+            parent: Some(("000000".to_string(), "havoc".to_string())),
+        };
+
+        let mut cache = FileHashCache::new();
+        assert_eq!(cache.lookup_nodes(&[&input], "afl", 1), vec![None]);
+        let hash = cache.calc_file_hash(&input).unwrap();
+        cache.insert_node(&input, "afl", node.clone());
+        cache.save(&cache_file).unwrap();
+
+        let mut cache = FileHashCache::load(&cache_file, HashAlgorithm::Sha1).unwrap();
+        assert_eq!(
+            cache.lookup_nodes(&[&input], "afl", 1),
+            vec![Some((node, hash))]
+        );
+        // Nodes parsed by other parsers are not used
+        assert_eq!(cache.lookup_nodes(&[&input], "afl:aurora", 1), vec![None]);
+        assert_eq!((cache.hits(), cache.misses()), (1, 0));
+
+        // Entries of deleted files are evicted on saving, if they are not looked up
+        std::fs::remove_file(&input).unwrap();
+        let cache = FileHashCache::load(&cache_file, HashAlgorithm::Sha1).unwrap();
+        assert_eq!(cache.len(), 1);
+        cache.save(&cache_file).unwrap();
+        let cache = FileHashCache::load(&cache_file, HashAlgorithm::Sha1).unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(cache.is_empty());
+    }
+}
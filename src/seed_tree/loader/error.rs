@@ -0,0 +1,80 @@
+use crate::seed_tree::loader::input_format::InputFormat;
+use crate::seed_tree::parser::error::ParseError;
+use crate::seed_tree::snapshot::error::SnapshotError;
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum LoadError {
+    IoError(io::Error),
+    ParseError(ParseError),
+    SnapshotError(SnapshotError),
+    InputNotExists(PathBuf),
+    // Inputs of different formats cannot be loaded into one graph
+    MixedInputFormats(InputFormat, InputFormat),
+    // Only AFL output directories can be loaded together
+    MultipleInputsNotSupported(InputFormat),
+    // Error on loading file
+    InFile(PathBuf, Box<LoadError>),
+}
+
+impl LoadError {
+    pub fn in_file<P: AsRef<Path>>(self, path: P) -> Self {
+        Self::InFile(path.as_ref().to_path_buf(), Box::new(self))
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IoError(error) => write!(f, "{}", error),
+            Self::ParseError(error) => write!(f, "{}", error),
+            Self::SnapshotError(error) => write!(f, "{}", error),
+            Self::InputNotExists(path) => write!(f, "input {} does not exist", path.display()),
+            Self::MixedInputFormats(a, b) => write!(
+                f,
+                "inputs of different formats cannot be loaded together: {} and {}",
+                a.name(),
+                b.name()
+            ),
+            Self::MultipleInputsNotSupported(format) => write!(
+                f,
+                "multiple inputs are supported only for afl, but got {}",
+                format.name()
+            ),
+            Self::InFile(path, error) => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::IoError(error) => Some(error),
+            Self::ParseError(error) => Some(error),
+            Self::SnapshotError(error) => Some(error),
+            Self::InFile(_, error) => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(error: io::Error) -> Self {
+        Self::IoError(error)
+    }
+}
+
+impl From<ParseError> for LoadError {
+    fn from(error: ParseError) -> Self {
+        Self::ParseError(error)
+    }
+}
+
+impl From<SnapshotError> for LoadError {
+    fn from(error: SnapshotError) -> Self {
+        Self::SnapshotError(error)
+    }
+}
@@ -0,0 +1,122 @@
+use super::error::LoadError;
+use super::result::Result;
+use crate::seed_tree::parser::honggfuzz::HONGGFUZZ_REPORT_FILE;
+use crate::seed_tree::snapshot;
+
+use std::ffi::OsStr;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputFormat {
+    /// AFL's output directory (or its `queue`, `crashes` subdirectory)
+    AFLDirectory,
+    /// File given to libFuzzer's `-mutation_graph_file` option
+    LibFuzzerMutationGraph,
+    /// LibAFL's on-disk corpus directory with `.<name>.metadata` files
+    LibAFLCorpus,
+    /// honggfuzz's workspace directory or its `HONGGFUZZ.REPORT.TXT`
+    Honggfuzz,
+    /// Seed tree saved by `save` subcommand
+    Snapshot,
+    /// Seed tree in DOT format wrapped by `digraph { ... }`
+    DotGraph,
+}
+
+impl InputFormat {
+    pub const NAMES: &'static [&'static str] =
+        &["afl", "libfuzzer", "libafl", "honggfuzz", "snapshot", "dot"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "afl" => Some(Self::AFLDirectory),
+            "libfuzzer" => Some(Self::LibFuzzerMutationGraph),
+            "libafl" => Some(Self::LibAFLCorpus),
+            "honggfuzz" => Some(Self::Honggfuzz),
+            "snapshot" => Some(Self::Snapshot),
+            "dot" => Some(Self::DotGraph),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::AFLDirectory => "afl",
+            Self::LibFuzzerMutationGraph => "libfuzzer",
+            Self::LibAFLCorpus => "libafl",
+            Self::Honggfuzz => "honggfuzz",
+            Self::Snapshot => "snapshot",
+            Self::DotGraph => "dot",
+        }
+    }
+
+    /// Whether multiple inputs of this format can be merged into one seed tree
+    pub fn supports_multiple_inputs(&self) -> bool {
+        matches!(
+            self,
+            Self::AFLDirectory
+                | Self::LibFuzzerMutationGraph
+                | Self::LibAFLCorpus
+                | Self::Honggfuzz
+        )
+    }
+
+    /// Guesses format of `path` from its type and leading bytes
+    pub fn detect<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Err(LoadError::InputNotExists(path.to_path_buf()));
+        }
+        if path.is_dir() {
+            return Self::detect_directory(path);
+        }
+        if path.file_name() == Some(OsStr::new(HONGGFUZZ_REPORT_FILE)) {
+            return Ok(Self::Honggfuzz);
+        }
+
+        let mut reader =
+            BufReader::new(File::open(path).map_err(|why| LoadError::from(why).in_file(path))?);
+        let head = reader
+            .fill_buf()
+            .map_err(|why| LoadError::from(why).in_file(path))?;
+        if head.starts_with(snapshot::MAGIC) {
+            return Ok(Self::Snapshot);
+        }
+
+        // NOTE: libFuzzer's mutation graph file has only node and edge statements without `digraph` header
+        // NOTE: Leading bytes may end in middle of a UTF-8 character or be binary
+        let mut head = Vec::new();
+        reader
+            .take(4096)
+            .read_to_end(&mut head)
+            .map_err(|why| LoadError::from(why).in_file(path))?;
+        match String::from_utf8_lossy(&head)
+            .lines()
+            .map(|v| v.trim())
+            .find(|v| !v.is_empty())
+        {
+            Some(line) if line.starts_with("digraph") => Ok(Self::DotGraph),
+            _ => Ok(Self::LibFuzzerMutationGraph),
+        }
+    }
+
+    // NOTE: Directories of other fuzzers are told by their own files, and the rest are AFL's
+    fn detect_directory(path: &Path) -> Result<Self> {
+        if path.join(HONGGFUZZ_REPORT_FILE).is_file() {
+            return Ok(Self::Honggfuzz);
+        }
+        let entries = path
+            .read_dir()
+            .map_err(|why| LoadError::from(why).in_file(path))?;
+        for entry in entries {
+            let entry = entry.map_err(|why| LoadError::from(why).in_file(path))?;
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if name.starts_with('.') && name.ends_with(".metadata") {
+                return Ok(Self::LibAFLCorpus);
+            }
+        }
+        Ok(Self::AFLDirectory)
+    }
+}
@@ -0,0 +1,224 @@
+pub mod error;
+pub mod input_format;
+pub mod result;
+
+use self::error::LoadError;
+use self::input_format::InputFormat;
+use self::result::Result;
+use crate::seed_tree::hash_cache::FileHashCache;
+use crate::seed_tree::parser::afl::{parse_afl_input_directories_with_cache, AFLExtensions};
+use crate::seed_tree::parser::generic::parse_generic_seed_tree_file;
+use crate::seed_tree::parser::honggfuzz::parse_honggfuzz_workspaces;
+use crate::seed_tree::parser::libafl::parse_libafl_corpus_directories_with_cache;
+use crate::seed_tree::parser::libfuzzer::parse_libfuzzer_mutation_graph_files;
+use crate::seed_tree::snapshot::load_snapshot;
+use crate::seed_tree::MutationGraph;
+
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+#[derive(Debug)]
+pub struct LoadOptions {
+    /// Format of inputs. Detected from each input if not given.
+    pub format: Option<InputFormat>,
+    pub afl_extensions: AFLExtensions,
+    /// Number of threads to parse AFL's input files
+    pub jobs: usize,
+}
+
+/// Loads seed tree from `inputs` of any supported format.
+/// Multiple AFL output directories, libFuzzer's mutation graph files (e.g. of fork mode jobs), LibAFL's corpus directories
+/// or honggfuzz's workspaces are merged into one seed tree. Empty `inputs` means DOT graph on `stdin`.
+pub fn load_inputs<P: AsRef<Path>, R: Read>(
+    inputs: &[P],
+    options: &LoadOptions,
+    cache: &mut FileHashCache,
+    stdin: R,
+) -> Result<MutationGraph> {
+    if inputs.is_empty() {
+        log::info!("Reading seed tree from stdin");
+        return Ok(parse_generic_seed_tree_file(stdin)?);
+    }
+
+    let mut format: Option<InputFormat> = None;
+    for input in inputs.iter() {
+        let input_format = match options.format {
+            Some(format) => format,
+            None => InputFormat::detect(input)?,
+        };
+        log::info!("Input {:?} is {:?}", input.as_ref(), input_format);
+        match format {
+            Some(format) if format != input_format => {
+                return Err(LoadError::MixedInputFormats(format, input_format))
+            }
+            _ => format = Some(input_format),
+        }
+    }
+    let format = format.expect("At least one input");
+
+    if !format.supports_multiple_inputs() && inputs.len() > 1 {
+        return Err(LoadError::MultipleInputsNotSupported(format));
+    }
+    let input = inputs[0].as_ref();
+
+    match format {
+        InputFormat::AFLDirectory => {
+            let mut directories: HashSet<&Path> = inputs.iter().map(|v| v.as_ref()).collect();
+            // NOTE: Crash input directory may be out of given directories
+            if let Some(ref crash_inputs_dir) = options.afl_extensions.crash_inputs_dir {
+                directories.insert(crash_inputs_dir.as_path());
+            }
+            Ok(parse_afl_input_directories_with_cache(
+                directories,
+                &options.afl_extensions,
+                cache,
+                options.jobs,
+            )?)
+        }
+        InputFormat::LibFuzzerMutationGraph => Ok(parse_libfuzzer_mutation_graph_files(inputs)?),
+        InputFormat::LibAFLCorpus => Ok(parse_libafl_corpus_directories_with_cache(
+            inputs,
+            cache,
+            options.jobs,
+        )?),
+        InputFormat::Honggfuzz => Ok(parse_honggfuzz_workspaces(inputs)?),
+        InputFormat::Snapshot => {
+            load_snapshot(input).map_err(|why| LoadError::from(why).in_file(input))
+        }
+        InputFormat::DotGraph => {
+            let file = File::open(input).map_err(|why| LoadError::from(why).in_file(input))?;
+            Ok(parse_generic_seed_tree_file(file).map_err(|why| why.in_file(input))?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::hash_cache::FileHashCache;
+    use crate::seed_tree::loader::error::LoadError;
+    use crate::seed_tree::loader::input_format::InputFormat;
+    use crate::seed_tree::loader::{load_inputs, LoadOptions};
+    use crate::seed_tree::parser::afl::AFLExtensions;
+    use crate::seed_tree::snapshot::save_snapshot;
+
+    const AFL_DIR: &str = "test/sample/seed-tree/aflplusplus-4.05c/";
+    const LIBFUZZER_FILE: &str = "test/sample/mutation_graph_file/graph1.dot";
+
+    fn options() -> LoadOptions {
+        LoadOptions {
+            format: None,
+            afl_extensions: AFLExtensions {
+                aurora: false,
+                crash_inputs_dir: None,
+            },
+            jobs: 1,
+        }
+    }
+
+    #[test]
+    fn test_detect_input_format() {
+        assert_eq!(
+            InputFormat::detect(AFL_DIR).unwrap(),
+            InputFormat::AFLDirectory
+        );
+        assert_eq!(
+            InputFormat::detect(LIBFUZZER_FILE).unwrap(),
+            InputFormat::LibFuzzerMutationGraph
+        );
+        assert!(matches!(
+            InputFormat::detect("test/sample/no_such_file"),
+            Err(LoadError::InputNotExists(_))
+        ));
+
+        let graph = load_inputs(
+            &[LIBFUZZER_FILE],
+            &options(),
+            &mut FileHashCache::new(),
+            std::io::empty(),
+        )
+        .unwrap();
+        let snapshot_file = std::env::temp_dir().join(format!(
+            "seed-tree-analyzer-test-loader-{}",
+            std::process::id()
+        ));
+        assert!(save_snapshot(&graph, &snapshot_file).is_ok());
+        assert_eq!(
+            InputFormat::detect(&snapshot_file).unwrap(),
+            InputFormat::Snapshot
+        );
+        let restored = load_inputs(
+            &[&snapshot_file],
+            &options(),
+            &mut FileHashCache::new(),
+            std::io::empty(),
+        );
+        let _ = std::fs::remove_file(&snapshot_file);
+        assert_eq!(restored.unwrap().roots(), graph.roots());
+
+        // Leading bytes ending in middle of a UTF-8 character
+        let dot_file = std::env::temp_dir().join(format!(
+            "seed-tree-analyzer-test-loader-dot-{}",
+            std::process::id()
+        ));
+        let mut dot = b"digraph {\n\"".to_vec();
+        dot.resize(4095, b'a');
+        dot.extend("\u{e9}\"\n}\n".as_bytes());
+        std::fs::write(&dot_file, &dot).unwrap();
+        let format = InputFormat::detect(&dot_file);
+        let _ = std::fs::remove_file(&dot_file);
+        assert_eq!(format.unwrap(), InputFormat::DotGraph);
+    }
+
+    #[test]
+    fn test_detect_other_fuzzers() {
+        let root = std::env::temp_dir().join(format!(
+            "seed-tree-analyzer-test-detect-{}",
+            std::process::id()
+        ));
+        let (libafl, honggfuzz) = (root.join("corpus"), root.join("workspace"));
+        std::fs::create_dir_all(&libafl).unwrap();
+        std::fs::create_dir_all(&honggfuzz).unwrap();
+        std::fs::write(libafl.join("seed"), "a").unwrap();
+        std::fs::write(libafl.join(".seed.metadata"), "{}").unwrap();
+        std::fs::write(honggfuzz.join("HONGGFUZZ.REPORT.TXT"), "").unwrap();
+
+        let detected = (
+            InputFormat::detect(&libafl),
+            InputFormat::detect(&honggfuzz),
+        );
+        let _ = std::fs::remove_dir_all(&root);
+        assert_eq!(detected.0.unwrap(), InputFormat::LibAFLCorpus);
+        assert_eq!(detected.1.unwrap(), InputFormat::Honggfuzz);
+    }
+
+    #[test]
+    fn test_load_mixed_inputs() {
+        assert!(matches!(
+            load_inputs(
+                &[AFL_DIR, LIBFUZZER_FILE],
+                &options(),
+                &mut FileHashCache::new(),
+                std::io::empty()
+            ),
+            Err(LoadError::MixedInputFormats(
+                InputFormat::AFLDirectory,
+                InputFormat::LibFuzzerMutationGraph
+            ))
+        ));
+        let dot_options = LoadOptions {
+            format: Some(InputFormat::DotGraph),
+            ..options()
+        };
+        assert!(matches!(
+            load_inputs(
+                &[LIBFUZZER_FILE, LIBFUZZER_FILE],
+                &dot_options,
+                &mut FileHashCache::new(),
+                std::io::empty()
+            ),
+            Err(LoadError::MultipleInputsNotSupported(InputFormat::DotGraph))
+        ));
+    }
+}
@@ -0,0 +1,3 @@
+use super::error::LoadError;
+
+pub type Result<T> = std::result::Result<T, LoadError>;
@@ -1,168 +1,620 @@
+pub mod compare;
+pub mod corpus;
 pub mod directed_edge;
+pub mod discovery_stats;
 pub mod error;
 pub mod file_hash;
+pub mod hash_algorithm;
+pub mod hash_cache;
+pub mod loader;
 pub mod mutation_graph_edge;
 pub mod mutation_graph_node;
+pub mod node_id;
 pub mod node_name;
 pub mod parser;
 pub mod plot_options;
+pub mod query;
 pub mod result;
+pub mod snapshot;
+pub mod sort_order;
+#[cfg(test)]
+pub(crate) mod test_util;
 pub mod util;
+pub mod weak_edge;
 
 use self::directed_edge::DirectedEdge;
 use self::error::MutationGraphError;
 use self::file_hash::FileHash;
 use self::mutation_graph_edge::MutationGraphEdge;
 use self::mutation_graph_node::MutationGraphNode;
+use self::node_id::{JobId, LabelId, NodeId, SourceId};
 use self::node_name::NodeName;
 use self::plot_options::PlotOptions;
 use self::result::Result;
+use self::sort_order::SortOrder;
+use self::weak_edge::{WeakEdge, WeakEdgeReason};
 
-use log::warn;
-use std::collections::hash_map::Values;
-use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
-use std::iter::FromIterator;
+use std::mem::size_of;
 
 #[derive(Debug, Clone)]
 pub struct MutationGraph {
-    // Stores real data
-    node: HashMap<NodeName, MutationGraphNode>,
-    edge: HashMap<DirectedEdge, MutationGraphEdge>,
-    weak_edge: HashMap<DirectedEdge, MutationGraphEdge>,
-
-    // Indexes to search nodes
-    children: HashMap<NodeName, HashSet<NodeName>>,
-    parent: HashMap<NodeName, NodeName>,
-    file_hash: HashMap<FileHash, NodeName>,
+    // Stores real data. Nodes and labels are interned and referred by their index.
+    node: Vec<MutationGraphNode>,
+    node_id: HashMap<NodeName, NodeId>,
+    label: Vec<String>,
+    label_id: HashMap<String, LabelId>,
+    // NOTE: Conflicting path is recorded on insertion, since later re-parents change tree edges it consists of
+    weak_edge: HashMap<(NodeId, NodeId), (LabelId, WeakEdgeReason, Vec<NodeId>)>,
+    // Jobs which each edge (including weak edge) comes from. Empty unless edges are added with jobs.
+    job: Vec<String>,
+    job_id: HashMap<String, JobId>,
+    edge_job: HashMap<(NodeId, NodeId), Vec<JobId>>,
+    // Sources which each node comes from. Empty unless seed trees are merged.
+    source: Vec<String>,
+    source_id: HashMap<String, SourceId>,
+    node_source: HashMap<NodeId, Vec<SourceId>>,
+    // Original names of nodes of merged seed trees which are unified with nodes of other names
+    merged_name: HashSet<NodeName>,
+
+    // Indexes to search nodes (indexed by `NodeId`).
+    // NOTE: Each node has at most one parent, so a (strong) edge is stored as pair of parent and label of its child.
+    children: Vec<Vec<NodeId>>,
+    parent: Vec<Option<(NodeId, LabelId)>>,
+    // Nodes sharing each contents in order of id
+    file_hash: HashMap<FileHash, Vec<NodeId>>,
+
+    // Union-find over trees to test whether two nodes share a root without walking to roots
+    tree: Vec<NodeId>,
+    tree_size: Vec<u32>,
 }
 
 impl MutationGraph {
     pub fn new() -> Self {
         Self {
-            node: HashMap::new(),
-            edge: HashMap::new(),
+            node: Vec::new(),
+            node_id: HashMap::new(),
+            label: Vec::new(),
+            label_id: HashMap::new(),
             weak_edge: HashMap::new(),
-            children: HashMap::new(),
-            parent: HashMap::new(),
+            job: Vec::new(),
+            job_id: HashMap::new(),
+            edge_job: HashMap::new(),
+            source: Vec::new(),
+            source_id: HashMap::new(),
+            node_source: HashMap::new(),
+            merged_name: HashSet::new(),
+            children: Vec::new(),
+            parent: Vec::new(),
             file_hash: HashMap::new(),
+            tree: Vec::new(),
+            tree_size: Vec::new(),
         }
     }
 
-    pub fn nodes(&self) -> Values<NodeName, MutationGraphNode> {
-        self.node.values()
+    /// Iterates nodes in order of insertion
+    pub fn nodes(&self) -> impl Iterator<Item = &MutationGraphNode> {
+        self.node.iter()
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = MutationGraphEdge> + '_ {
+        self.parent
+            .iter()
+            .enumerate()
+            .filter_map(move |(child, parent)| {
+                parent.map(|(parent, label)| self.edge_of(parent, NodeId::from(child), label))
+            })
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.node.len()
+    }
+
+    pub fn id_of(&self, name: &NodeName) -> Option<NodeId> {
+        self.node_id.get(name).cloned()
+    }
+
+    pub fn name_of(&self, id: NodeId) -> &NodeName {
+        &self.node[id.index()].name
+    }
+
+    fn edge_of(&self, parent: NodeId, child: NodeId, label: LabelId) -> MutationGraphEdge {
+        MutationGraphEdge {
+            parent: self.name_of(parent).clone(),
+            child: self.name_of(child).clone(),
+            label: self.label[label.index()].clone(),
+        }
+    }
+
+    // Returns id of node named `name`, declaring the node if not exists
+    fn intern_node(&mut self, name: &NodeName) -> NodeId {
+        match self.node_id.get(name) {
+            Some(id) => *id,
+            None => {
+                let id = NodeId::from(self.node.len());
+                self.node.push(MutationGraphNode::new(name));
+                self.node_id.insert(name.clone(), id);
+                self.children.push(Vec::new());
+                self.parent.push(None);
+                // Each node forms a tree on first time
+                self.tree.push(id);
+                self.tree_size.push(1);
+                id
+            }
+        }
     }
 
-    pub fn edges(&self) -> Values<DirectedEdge, MutationGraphEdge> {
-        self.edge.values()
+    fn intern_label(&mut self, label: &String) -> LabelId {
+        match self.label_id.get(label) {
+            Some(id) => *id,
+            None => {
+                let id = LabelId::from(self.label.len());
+                self.label.push(label.clone());
+                self.label_id.insert(label.clone(), id);
+                id
+            }
+        }
     }
 
     pub fn add_node(&mut self, node: &MutationGraphNode) -> () {
         // NOTE: *Last* inserted node overwhelms existing node when nodes with same name are inserted
-        self.node.insert(node.name.clone(), node.clone());
-        if !self.children.contains_key(&node.name) {
-            // Initialize children on first time
-            self.children.insert(node.name.clone(), HashSet::new());
+        let id = self.intern_node(&node.name);
+        let old = std::mem::replace(&mut self.node[id.index()], node.clone());
+        if old.hash != node.hash {
+            if let Some(ids) = self.file_hash.get_mut(&old.hash) {
+                ids.retain(|v| *v != id);
+                if ids.is_empty() {
+                    self.file_hash.remove(&old.hash);
+                }
+            }
         }
+        // NOTE: Nodes sharing contents (e.g. inputs rediscovered via different lineages) are all kept
         if !node.hash.is_empty() {
-            if let Some(collision_node) =
-                self.file_hash.insert(node.hash.clone(), node.name.clone())
-            {
-                warn!(
-                    "Inserted node collides with node name={:?}: node={:?}",
-                    collision_node, node
-                );
+            let ids = self.file_hash.entry(node.hash.clone()).or_default();
+            if let Err(position) = ids.binary_search(&id) {
+                ids.insert(position, id);
             }
         }
     }
 
     pub fn add_edge(&mut self, edge: &MutationGraphEdge) -> () {
         // Some times explicit node declarations are missed in original mutation graph node
-        if self.get_node(&edge.parent).is_none() {
-            self.add_node(&MutationGraphNode::new(&edge.parent))
+        let parent = self.intern_node(&edge.parent);
+        let child = self.intern_node(&edge.child);
+        let label = self.intern_label(&edge.label);
+
+        // Insert edge and update indexes avoiding making closed chains
+        match self.conflict_of(parent, child) {
+            None => {
+                // NOTE: *Last* parent wins when a node gets a parent in another tree,
+                //       and edge from its former parent is kept as weak edge
+                if let Some((existing, existing_label)) = self.parent[child.index()] {
+                    self.children[existing.index()].retain(|v| *v != child);
+                    self.weak_edge.insert(
+                        (existing, child),
+                        (
+                            existing_label,
+                            WeakEdgeReason::Reparent,
+                            vec![parent, child],
+                        ),
+                    );
+                }
+                self.children[parent.index()].push(child);
+                self.parent[child.index()] = Some((parent, label));
+                self.merge_trees(parent, child);
+            }
+            Some(reason) => {
+                let path = self.conflicting_path_of(parent, child, reason);
+                self.weak_edge
+                    .insert((parent, child), (label, reason, path));
+            }
         }
-        if self.get_node(&edge.child).is_none() {
-            self.add_node(&MutationGraphNode::new(&edge.child))
+    }
+
+    // Returns why edge from `parent` to `child` can not be a tree edge
+    fn conflict_of(&mut self, parent: NodeId, child: NodeId) -> Option<WeakEdgeReason> {
+        if parent == child {
+            return Some(WeakEdgeReason::SelfLoop);
         }
+        let existing = self.parent[child.index()];
+        match existing {
+            Some((p, _)) if p == parent => Some(WeakEdgeReason::Duplicate),
+            // NOTE: Union-find never splits trees of re-parented nodes, so it tells only that
+            //       nodes may share a root. Walk to roots only in that case.
+            _ if self.same_tree(parent, child)
+                && self.root_id_of(parent) == self.root_id_of(child) =>
+            {
+                if self.is_ancestor_of(child, parent) {
+                    Some(WeakEdgeReason::ClosedChain)
+                } else {
+                    Some(WeakEdgeReason::Reparent)
+                }
+            }
+            _ => None,
+        }
+    }
 
-        // Insert edge and update indexes avoiding making closed chains
-        if self.root_of(&edge.parent) != self.root_of(&edge.child) {
-            self.edge.insert(DirectedEdge::from(&edge), edge.clone());
+    fn root_id_of(&self, node: NodeId) -> NodeId {
+        let mut current = node;
+        for _ in 0..self.node.len() {
+            match self.parent_id_of(current) {
+                Some(parent) => current = parent,
+                None => break,
+            }
+        }
+        current
+    }
+
+    fn is_ancestor_of(&self, ancestor: NodeId, node: NodeId) -> bool {
+        let mut current = node;
+        for _ in 0..self.node.len() {
+            match self.parent_id_of(current) {
+                Some(parent) if parent == ancestor => return true,
+                Some(parent) => current = parent,
+                None => return false,
+            }
+        }
+        false
+    }
+
+    // Path of current tree edges conflicting with edge from `parent` to `child`
+    fn conflicting_path_of(
+        &self,
+        parent: NodeId,
+        child: NodeId,
+        reason: WeakEdgeReason,
+    ) -> Vec<NodeId> {
+        match reason {
+            WeakEdgeReason::SelfLoop => vec![parent],
+            WeakEdgeReason::ClosedChain => {
+                let mut path = vec![parent];
+                let mut current = parent;
+                while current != child {
+                    match self.parent_id_of(current) {
+                        Some(next) if path.len() <= self.node.len() => {
+                            path.push(next);
+                            current = next;
+                        }
+                        _ => break,
+                    }
+                }
+                path.reverse(); // Order from child
+                path
+            }
+            WeakEdgeReason::Duplicate | WeakEdgeReason::Reparent => {
+                match self.parent_id_of(child) {
+                    Some(existing) => vec![existing, child],
+                    None => vec![child],
+                }
+            }
+            WeakEdgeReason::Declared => Vec::new(),
+        }
+    }
+
+    /// Lists weak edges with why they are not tree edges, ordered by names of parent and child
+    pub fn weak_edges(&self) -> Vec<WeakEdge> {
+        let mut res: Vec<WeakEdge> = self
+            .weak_edge
+            .iter()
+            .map(|((parent, child), (label, reason, path))| WeakEdge {
+                edge: self.edge_of(*parent, *child, *label),
+                reason: *reason,
+                path: path.iter().map(|id| self.name_of(*id).clone()).collect(),
+            })
+            .collect();
+        res.sort_by(|a, b| a.edge.cmp(&b.edge));
+        res
+    }
+
+    // Finds representative of tree which `node` belongs to, compressing paths on the way
+    fn find_tree(&mut self, node: NodeId) -> NodeId {
+        let mut representative = node;
+        while self.tree[representative.index()] != representative {
+            representative = self.tree[representative.index()];
+        }
+
+        let mut current = node;
+        while current != representative {
+            let next = self.tree[current.index()];
+            self.tree[current.index()] = representative;
+            current = next;
+        }
+
+        representative
+    }
+
+    fn same_tree(&mut self, a: NodeId, b: NodeId) -> bool {
+        self.find_tree(a) == self.find_tree(b)
+    }
+
+    fn merge_trees(&mut self, a: NodeId, b: NodeId) {
+        let a = self.find_tree(a);
+        let b = self.find_tree(b);
+        if a == b {
+            return;
+        }
+        // Union by size
+        let (large, small) = if self.tree_size[a.index()] < self.tree_size[b.index()] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.tree_size[large.index()] += self.tree_size[small.index()];
+        self.tree[small.index()] = large;
+    }
+
+    /// Adds `edge` recording that it comes from `job`. Jobs of an edge declared by several jobs are all recorded.
+    pub fn add_edge_of_job(&mut self, edge: &MutationGraphEdge, job: &str) {
+        let arrow = DirectedEdge::from(edge);
+        // NOTE: Edge already declared by another job is not a duplicate, so only its job is recorded
+        if self.get_edge(&arrow).is_none() {
+            self.add_edge(edge);
+        }
+        self.record_job_of(&arrow, job);
+    }
+
+    fn intern_job(&mut self, job: &str) -> JobId {
+        match self.job_id.get(job) {
+            Some(id) => *id,
+            None => {
+                let id = JobId::from(self.job.len());
+                self.job.push(job.to_string());
+                self.job_id.insert(job.to_string(), id);
+                id
+            }
+        }
+    }
 
-            match self.children.get_mut(&edge.parent) {
-                Some(children) => {
-                    children.insert(edge.child.clone());
+    fn record_job_of(&mut self, edge: &DirectedEdge, job: &str) {
+        let parent = self.intern_node(&edge.parent);
+        let child = self.intern_node(&edge.child);
+        let job = self.intern_job(job);
+        let jobs = self.edge_job.entry((parent, child)).or_default();
+        if !jobs.contains(&job) {
+            jobs.push(job);
+        }
+    }
+
+    /// Jobs in order of first appearance
+    pub fn jobs(&self) -> &[String] {
+        &self.job
+    }
+
+    /// Jobs which `edge` comes from in order of appearance
+    pub fn jobs_of(&self, edge: &DirectedEdge) -> Vec<&String> {
+        let key = match (self.id_of(&edge.parent), self.id_of(&edge.child)) {
+            (Some(parent), Some(child)) => (parent, child),
+            _ => return Vec::new(),
+        };
+        match self.edge_job.get(&key) {
+            Some(jobs) => jobs.iter().map(|job| &self.job[job.index()]).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn intern_source(&mut self, source: &str) -> SourceId {
+        match self.source_id.get(source) {
+            Some(id) => *id,
+            None => {
+                let id = SourceId::from(self.source.len());
+                self.source.push(source.to_string());
+                self.source_id.insert(source.to_string(), id);
+                id
+            }
+        }
+    }
+
+    fn record_source_of(&mut self, node: NodeId, source: &str) {
+        let source = self.intern_source(source);
+        let sources = self.node_source.entry(node).or_default();
+        if !sources.contains(&source) {
+            sources.push(source);
+        }
+    }
+
+    /// Sources of merged seed trees in order of merge
+    pub fn sources(&self) -> &[String] {
+        &self.source
+    }
+
+    /// Sources which node `name` comes from in order of merge
+    pub fn sources_of(&self, name: &NodeName) -> Vec<&String> {
+        match self.id_of(name).and_then(|id| self.node_source.get(&id)) {
+            Some(sources) => sources
+                .iter()
+                .map(|source| &self.source[source.index()])
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Merges `other` seed tree which comes from `source` (e.g. libFuzzer's mutation graph file or AFL's output directory).
+    ///
+    /// Nodes of `other` are unified with nodes of this graph having same name or same file hash, so inputs found
+    /// by different fuzzers are merged into one node. Nodes whose names are used by nodes with different contents or by
+    /// nodes unified before (e.g. AFL's ids of different campaigns) are renamed to `<source>:<name>`. `source` is recorded
+    /// to each node of `other` (see `sources_of()`). Sources already recorded in `other` are kept instead.
+    /// Edges conflicting with existing ones become weak edges.
+    pub fn merge(&mut self, other: &MutationGraph, source: &str) {
+        // NOTE: Nodes are unified only with nodes existing before merge, since nodes in `other` sharing contents
+        //       (e.g. AFL's initial seed and its copy in queue) are distinct
+        let existing = self.node.len();
+        let mut unified: HashSet<NodeId> = HashSet::new();
+        let mut name_of: Vec<NodeName> = Vec::with_capacity(other.node.len());
+        for (index, node) in other.node.iter().enumerate() {
+            let same_contents = |id: &NodeId| {
+                let hash = &self.node[id.index()].hash;
+                hash.is_empty() || node.hash.is_empty() || hash == &node.hash
+            };
+            let by_name = self.id_of(&node.name).filter(|id| id.index() < existing);
+            let by_hash = self.file_hash.get(&node.hash).and_then(|ids| {
+                ids.iter()
+                    .find(|id| id.index() < existing && !unified.contains(id))
+                    .cloned()
+            });
+            let id = match (by_name, by_hash) {
+                (Some(id), _) if same_contents(&id) => Some(id),
+                (_, Some(id)) => Some(id),
+                _ => None,
+            };
+            let id = match id {
+                Some(id) => {
+                    let merged = self.node[id.index()].merged_with(node);
+                    self.add_node(&merged);
+                    unified.insert(id);
+                    if self.name_of(id) != &node.name {
+                        self.merged_name.insert(node.name.clone());
+                    }
+                    id
                 }
                 None => {
-                    self.children.insert(
-                        edge.parent.clone(),
-                        HashSet::from_iter([edge.child.clone()].iter().cloned()),
-                    );
+                    // NOTE: Names of unified nodes still refer them (e.g. by users knowing AFL's ids),
+                    //       so they are not reused by other nodes
+                    let name = if by_name.is_some() || self.merged_name.contains(&node.name) {
+                        format!("{}:{}", source, node.name)
+                    } else {
+                        node.name.clone()
+                    };
+                    self.add_node(&MutationGraphNode {
+                        name: name.clone(),
+                        ..node.clone()
+                    });
+                    self.id_of(&name).expect("Node added above")
                 }
             };
+            // NOTE: Sources of nodes of merged seed tree (e.g. loaded from snapshot) are kept as is
+            match other.node_source.get(&NodeId::from(index)) {
+                Some(sources) => {
+                    for other_source in sources.iter() {
+                        self.record_source_of(id, &other.source[other_source.index()]);
+                    }
+                }
+                None => self.record_source_of(id, source),
+            }
+            name_of.push(self.name_of(id).clone());
+        }
 
-            self.parent.insert(edge.child.clone(), edge.parent.clone());
-        } else {
-            self.add_weak_edge(edge);
+        let map = |edge: MutationGraphEdge| MutationGraphEdge {
+            parent: name_of[other.node_id[&edge.parent].index()].clone(),
+            child: name_of[other.node_id[&edge.child].index()].clone(),
+            label: edge.label,
+        };
+        for edge in other.edges() {
+            let edge = map(edge);
+            // NOTE: Edges found by several sources are kept as tree edges rather than duplicate weak edges
+            if self.get_edge(&DirectedEdge::from(&edge)).is_none() {
+                self.add_edge(&edge);
+            }
+        }
+        let mut weak_edges: Vec<_> = other.weak_edge.iter().collect();
+        weak_edges.sort();
+        for ((parent, child), (label, ..)) in weak_edges {
+            self.add_weak_edge(&map(other.edge_of(*parent, *child, *label)));
+        }
+        let mut edge_jobs: Vec<_> = other.edge_job.iter().collect();
+        edge_jobs.sort();
+        for ((parent, child), jobs) in edge_jobs {
+            let edge = DirectedEdge::new(&name_of[parent.index()], &name_of[child.index()]);
+            for job in jobs {
+                self.record_job_of(&edge, &other.job[job.index()]);
+            }
         }
     }
 
     pub fn add_weak_edge(&mut self, edge: &MutationGraphEdge) {
+        let parent = self.intern_node(&edge.parent);
+        let child = self.intern_node(&edge.child);
+        let label = self.intern_label(&edge.label);
+        // NOTE: Reason is recovered when tree edges are added before (e.g. extracting subgraph)
+        let reason = match self.conflict_of(parent, child) {
+            Some(reason) => reason,
+            // NOTE: Edge from former parent of re-parented node does not conflict with current trees
+            None if self.parent[child.index()].is_some() => WeakEdgeReason::Reparent,
+            None => WeakEdgeReason::Declared,
+        };
+        let path = self.conflicting_path_of(parent, child, reason);
         self.weak_edge
-            .insert(DirectedEdge::from(&edge), edge.clone());
+            .insert((parent, child), (label, reason, path));
     }
 
     pub fn get_node(&self, sha1: &NodeName) -> Option<&MutationGraphNode> {
-        self.node.get(sha1)
+        self.node_id.get(sha1).map(|id| &self.node[id.index()])
     }
 
-    pub fn get_edge(&self, arrow: &DirectedEdge) -> Option<&MutationGraphEdge> {
-        self.edge.get(arrow)
+    pub fn get_edge(&self, arrow: &DirectedEdge) -> Option<MutationGraphEdge> {
+        let parent = self.id_of(&arrow.parent)?;
+        let child = self.id_of(&arrow.child)?;
+        match self.parent[child.index()] {
+            Some((p, label)) if p == parent => Some(self.edge_of(parent, child, label)),
+            _ => None,
+        }
     }
 
-    pub fn children_of(&self, parent: &NodeName) -> Option<&HashSet<NodeName>> {
-        self.children.get(parent)
+    pub fn children_of(&self, parent: &NodeName) -> Option<Vec<&NodeName>> {
+        self.id_of(parent).map(|id| {
+            self.children[id.index()]
+                .iter()
+                .map(|child| self.name_of(*child))
+                .collect()
+        })
     }
 
     pub fn parent_of(&self, child: &NodeName) -> Option<&NodeName> {
-        self.parent.get(child)
+        let id = self.id_of(child)?;
+        self.parent[id.index()].map(|(parent, _)| self.name_of(parent))
     }
 
-    pub fn root_of<'a>(&'a self, node: &'a NodeName) -> Result<&'a NodeName> {
-        if self.get_node(node).is_none() {
-            return Err(MutationGraphError::NodeNotExists(node.clone()));
-        }
-        match self.parent_of(node) {
-            Some(parent) => self.root_of(parent),
-            None => Ok(node),
-        }
+    fn parent_id_of(&self, child: NodeId) -> Option<NodeId> {
+        self.parent[child.index()].map(|(parent, _)| parent)
     }
 
-    fn __rank_of(&self, node: &NodeName, rank: usize) -> Result<usize> {
-        match self.parent_of(node) {
-            Some(parent) => self.__rank_of(parent, rank + 1),
-            None => Ok(rank), // If given node is root, then rank is 0.
+    pub fn root_of<'a>(&'a self, node: &'a NodeName) -> Result<&'a NodeName> {
+        let id = match self.id_of(node) {
+            Some(id) => id,
+            None => return Err(MutationGraphError::NodeNotExists(node.clone())),
+        };
+        let mut current = id;
+        for _ in 0..=self.node.len() {
+            match self.parent_id_of(current) {
+                Some(parent) => current = parent,
+                None => return Ok(self.name_of(current)),
+            }
         }
+        Err(MutationGraphError::CycleDetected(node.clone()))
     }
 
     pub fn rank_of(&self, node: &NodeName) -> Result<usize> {
-        self.__rank_of(node, 0)
+        let mut rank = 0; // If given node is root, then rank is 0.
+        let mut current = match self.id_of(node) {
+            Some(id) => id,
+            None => return Ok(rank),
+        };
+        while let Some(parent) = self.parent_id_of(current) {
+            rank += 1;
+            if rank > self.node.len() {
+                return Err(MutationGraphError::CycleDetected(node.clone()));
+            }
+            current = parent;
+        }
+        Ok(rank)
     }
 
     pub fn predecessors_of(&self, node: &NodeName) -> Result<Vec<&NodeName>> {
-        if self.get_node(node).is_none() {
-            return Err(MutationGraphError::NodeNotExists(node.clone()));
-        }
-        match self.parent_of(node) {
-            Some(parent) => match self.predecessors_of(parent) {
-                Ok(mut res) => {
-                    res.push(parent);
-                    Ok(res)
-                }
-                Err(why) => return Err(why),
-            },
-            None => Ok(vec![]),
+        let mut current = match self.id_of(node) {
+            Some(id) => id,
+            None => return Err(MutationGraphError::NodeNotExists(node.clone())),
+        };
+        let mut res = Vec::new();
+        while let Some(parent) = self.parent_id_of(current) {
+            if res.len() >= self.node.len() {
+                return Err(MutationGraphError::CycleDetected(node.clone()));
+            }
+            res.push(self.name_of(parent));
+            current = parent;
         }
+        res.reverse(); // Order from root
+        Ok(res)
     }
 
     pub fn self_and_its_predecessors_of(&self, node: &NodeName) -> Result<Vec<&NodeName>> {
@@ -177,25 +629,290 @@ impl MutationGraph {
     pub fn leaves(&self) -> HashSet<&NodeName> {
         self.children
             .iter()
+            .enumerate()
             .filter(|(_, v)| v.len() == 0)
-            .map(|(k, _)| k)
+            .map(|(id, _)| self.name_of(NodeId::from(id)))
             .collect()
     }
 
     pub fn roots(&self) -> HashSet<&NodeName> {
-        self.node
-            .keys()
-            .filter(|v| self.parent_of(v).is_none())
+        self.parent
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.is_none())
+            .map(|(id, _)| self.name_of(NodeId::from(id)))
             .collect()
     }
 
+    /// Returns first node having `file_hash` in order of id.
+    /// Initial seeds are returned only when no other node has same contents (e.g. their copies in AFL's queue).
     pub fn lookup_by_file_hash(&self, file_hash: &FileHash) -> Result<&NodeName> {
         match self.file_hash.get(file_hash) {
-            Some(node_name) => Ok(node_name),
+            Some(ids) => {
+                let id = ids
+                    .iter()
+                    .find(|id| !self.node[id.index()].initial_seed)
+                    .unwrap_or(&ids[0]);
+                Ok(self.name_of(*id))
+            }
             None => Err(MutationGraphError::FileHashNotExists(file_hash.clone())),
         }
     }
 
+    /// Looks up node by leading hex digits of its file hash (e.g. `3a5443`) as `lookup_by_file_hash()`.
+    /// Fails if hashes of multiple contents start with `prefix`.
+    pub fn lookup_by_file_hash_prefix(&self, prefix: &str) -> Result<&NodeName> {
+        let mut hashes: Vec<&FileHash> = if prefix.is_empty() {
+            Vec::new()
+        } else {
+            self.file_hash
+                .keys()
+                .filter(|v| v.starts_with_hex(prefix))
+                .collect()
+        };
+        match hashes.len() {
+            0 => Err(MutationGraphError::FileHashPrefixNotExists(
+                prefix.to_string(),
+            )),
+            1 => self.lookup_by_file_hash(hashes[0]),
+            _ => {
+                hashes.sort();
+                Err(MutationGraphError::AmbiguousFileHash(
+                    prefix.to_string(),
+                    hashes.into_iter().cloned().collect(),
+                ))
+            }
+        }
+    }
+
+    /// Nodes having `file_hash` in order of id
+    pub fn nodes_with_hash(&self, file_hash: &FileHash) -> Vec<&NodeName> {
+        match self.file_hash.get(file_hash) {
+            Some(ids) => ids.iter().map(|id| self.name_of(*id)).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Groups of two or more nodes sharing contents, in order of their first nodes. Nodes in each group are in order of id.
+    pub fn duplicates(&self) -> Vec<Vec<&NodeName>> {
+        let mut groups: Vec<&Vec<NodeId>> = self
+            .file_hash
+            .values()
+            .filter(|ids| ids.len() > 1)
+            .collect();
+        groups.sort();
+        groups
+            .into_iter()
+            .map(|ids| ids.iter().map(|id| self.name_of(*id)).collect())
+            .collect()
+    }
+
+    /// Returns induced subgraph consists of `names`.
+    /// Nodes are added in same order as this graph to keep their relative ids.
+    pub fn subgraph(&self, names: &HashSet<&NodeName>) -> MutationGraph {
+        let mut graph = MutationGraph::new();
+        for node in self.nodes() {
+            if names.contains(&node.name) {
+                graph.add_node(node);
+            }
+        }
+        for edge in self.edges() {
+            if names.contains(&edge.parent) && names.contains(&edge.child) {
+                graph.add_edge(&edge);
+            }
+        }
+        let mut weak_edges: Vec<_> = self.weak_edge.iter().collect();
+        weak_edges.sort();
+        for ((parent, child), (label, ..)) in weak_edges {
+            if names.contains(self.name_of(*parent)) && names.contains(self.name_of(*child)) {
+                graph.add_weak_edge(&self.edge_of(*parent, *child, *label));
+            }
+        }
+        // NOTE: Jobs are interned in same order as this graph
+        for job in self.job.iter() {
+            graph.intern_job(job);
+        }
+        let mut edge_jobs: Vec<_> = self.edge_job.iter().collect();
+        edge_jobs.sort();
+        for ((parent, child), jobs) in edge_jobs {
+            let (parent, child) = (self.name_of(*parent), self.name_of(*child));
+            if names.contains(parent) && names.contains(child) {
+                let edge = DirectedEdge::new(parent, child);
+                for job in jobs {
+                    graph.record_job_of(&edge, &self.job[job.index()]);
+                }
+            }
+        }
+        // NOTE: Sources are interned in same order as this graph
+        for source in self.source.iter() {
+            graph.intern_source(source);
+        }
+        let mut node_sources: Vec<_> = self.node_source.iter().collect();
+        node_sources.sort();
+        for (node, sources) in node_sources {
+            if let Some(id) = graph.id_of(self.name_of(*node)) {
+                for source in sources {
+                    graph.record_source_of(id, &self.source[source.index()]);
+                }
+            }
+        }
+        graph
+    }
+
+    /// Estimates heap memory used by this graph in bytes
+    pub fn approximate_memory_usage(&self) -> usize {
+        // NOTE: Capacity of hash tables are approximated by their length
+        let names: usize = self.node.iter().map(|v| v.name.capacity()).sum();
+        let nodes: usize = self.node.iter().map(|v| v.file.capacity()).sum::<usize>()
+            + self.node.capacity() * size_of::<MutationGraphNode>();
+        let labels: usize = self.label.iter().map(|v| v.capacity() * 2).sum::<usize>()
+            + self.label.capacity() * size_of::<String>()
+            + self.label_id.len() * (size_of::<String>() + size_of::<LabelId>());
+        let node_id = names + self.node_id.len() * (size_of::<NodeName>() + size_of::<NodeId>());
+        let edges = self
+            .weak_edge
+            .values()
+            .map(|(.., path)| path.capacity() * size_of::<NodeId>())
+            .sum::<usize>()
+            + self.weak_edge.len()
+                * (size_of::<(NodeId, NodeId)>()
+                    + size_of::<(LabelId, WeakEdgeReason, Vec<NodeId>)>())
+            + self.parent.capacity() * size_of::<Option<(NodeId, LabelId)>>()
+            + self
+                .children
+                .iter()
+                .map(|v| v.capacity() * size_of::<NodeId>())
+                .sum::<usize>()
+            + self.children.capacity() * size_of::<Vec<NodeId>>();
+        let file_hash: usize = self
+            .file_hash
+            .iter()
+            .map(|(_, v)| {
+                size_of::<FileHash>()
+                    + size_of::<Vec<NodeId>>()
+                    + v.capacity() * size_of::<NodeId>()
+            })
+            .sum();
+        let jobs = self.job.iter().map(|v| v.capacity() * 2).sum::<usize>()
+            + self
+                .edge_job
+                .values()
+                .map(|v| {
+                    size_of::<(NodeId, NodeId)>()
+                        + size_of::<Vec<JobId>>()
+                        + v.capacity() * size_of::<JobId>()
+                })
+                .sum::<usize>();
+        let sources = self.source.iter().map(|v| v.capacity() * 2).sum::<usize>()
+            + self
+                .node_source
+                .values()
+                .map(|v| {
+                    size_of::<NodeId>()
+                        + size_of::<Vec<SourceId>>()
+                        + v.capacity() * size_of::<SourceId>()
+                })
+                .sum::<usize>()
+            + self
+                .merged_name
+                .iter()
+                .map(|v| v.capacity() + size_of::<NodeName>())
+                .sum::<usize>();
+        let tree = self.tree.capacity() * size_of::<NodeId>()
+            + self.tree_size.capacity() * size_of::<u32>();
+        names + nodes + labels + node_id + edges + file_hash + jobs + sources + tree
+    }
+
+    // Returns ids of all nodes in given order
+    fn sorted_ids(&self, order: SortOrder) -> Vec<NodeId> {
+        let mut ids: Vec<NodeId> = (0..self.node.len()).map(NodeId::from).collect();
+        match order {
+            SortOrder::Name => ids.sort_by(|a, b| self.name_of(*a).cmp(self.name_of(*b))),
+            SortOrder::Id => (),
+            SortOrder::Discovery => ids.sort_by_key(|id| (self.node[id.index()].discovered, *id)),
+            SortOrder::Topological => {
+                let by_name = |ids: &mut Vec<NodeId>| {
+                    ids.sort_by(|a, b| self.name_of(*b).cmp(self.name_of(*a)))
+                };
+                // Depth-first pre-order. NOTE: Stack is sorted in reverse to pop smallest name first
+                let mut stack: Vec<NodeId> = ids
+                    .iter()
+                    .filter(|id| self.parent[id.index()].is_none())
+                    .cloned()
+                    .collect();
+                by_name(&mut stack);
+                ids.clear();
+                while let Some(id) = stack.pop() {
+                    ids.push(id);
+                    let mut children = self.children[id.index()].clone();
+                    by_name(&mut children);
+                    stack.extend(children);
+                }
+            }
+        }
+        ids
+    }
+
+    // Returns position of each node (indexed by `NodeId`) in given order
+    fn positions(&self, order: SortOrder) -> Vec<usize> {
+        let mut positions = vec![0; self.node.len()];
+        for (position, id) in self.sorted_ids(order).iter().enumerate() {
+            positions[id.index()] = position;
+        }
+        positions
+    }
+
+    /// Sorts `names` in given order. Names not in this graph follow others in order of name.
+    pub fn sort_names<'a, I: IntoIterator<Item = &'a NodeName>>(
+        &self,
+        names: I,
+        order: SortOrder,
+    ) -> Vec<&'a NodeName> {
+        let positions = self.positions(order);
+        let mut names: Vec<&'a NodeName> = names.into_iter().collect();
+        names.sort_by_key(|name| match self.id_of(name) {
+            Some(id) => (Some(positions[id.index()]), *name),
+            None => (None, *name),
+        });
+        // NOTE: `None` precedes `Some`, so move names not in this graph to the end
+        let unknown = names.iter().take_while(|v| self.id_of(v).is_none()).count();
+        names.rotate_left(unknown);
+        names
+    }
+
+    pub fn sorted_nodes(&self, order: SortOrder) -> Vec<&MutationGraphNode> {
+        self.sorted_ids(order)
+            .iter()
+            .map(|id| &self.node[id.index()])
+            .collect()
+    }
+
+    // Sorts edges by positions of their parent and then child
+    fn sort_edges(&self, edges: &mut Vec<(NodeId, NodeId, LabelId)>, positions: &[usize]) {
+        edges.sort_by_key(|(parent, child, _)| {
+            (positions[parent.index()], positions[child.index()])
+        });
+    }
+
+    pub fn sorted_edges(&self, order: SortOrder) -> Vec<MutationGraphEdge> {
+        let mut edges = self.edge_ids();
+        self.sort_edges(&mut edges, &self.positions(order));
+        edges
+            .into_iter()
+            .map(|(parent, child, label)| self.edge_of(parent, child, label))
+            .collect()
+    }
+
+    fn edge_ids(&self) -> Vec<(NodeId, NodeId, LabelId)> {
+        self.parent
+            .iter()
+            .enumerate()
+            .filter_map(|(child, parent)| {
+                parent.map(|(parent, label)| (parent, NodeId::from(child), label))
+            })
+            .collect()
+    }
+
     // Dumps self to dot graph
     pub fn dot_graph(&self, plot_options: PlotOptions) -> Result<String> {
         let predecessors = match plot_options.highlight_edges_from_root_to {
@@ -203,6 +920,7 @@ impl MutationGraph {
             None => vec![],
         };
 
+        let positions = self.positions(plot_options.sort_order);
         let mut res = String::new();
 
         // Start of dot file
@@ -210,7 +928,9 @@ impl MutationGraph {
 
         // Add notes
         // NOTE: Add notes first to place notes in preference to edges.
-        for (node, label) in plot_options.notate.iter() {
+        let notes = self.sort_names(plot_options.notate.keys(), plot_options.sort_order);
+        for node in notes {
+            let label = &plot_options.notate[node];
             write!(
                 &mut res,
                 "{{rank=same; \"note_{node}\" [label=\"{label}\", shape=plaintext, fontname=\"sans-serif\", fontsize=11.0, style=filled, fillcolor=cornsilk];\n\"note_{node}\" -> \"{node}\" [color=black, style=dashed, arrowhead=none, splines=curved]}};\n",
@@ -220,8 +940,7 @@ impl MutationGraph {
         }
 
         // Declare nodes
-        let node_heap: BinaryHeap<&MutationGraphNode> = self.node.values().map(|v| v).collect();
-        for node in node_heap.into_iter_sorted() {
+        for node in self.sorted_nodes(plot_options.sort_order) {
             let mut additional = String::new();
             if let Some(ref target) = plot_options.highlight_edges_from_root_to {
                 if &node.name == target {
@@ -240,8 +959,10 @@ impl MutationGraph {
         }
 
         // Declare edges
-        let edge_heap: BinaryHeap<&MutationGraphEdge> = self.edge.values().map(|v| v).collect();
-        for edge in edge_heap.into_iter_sorted() {
+        let mut edges = self.edge_ids();
+        self.sort_edges(&mut edges, &positions);
+        for (parent, child, label) in edges {
+            let edge = &self.edge_of(parent, child, label);
             let mut additional = String::new();
             if let Some(ref target) = plot_options.highlight_edges_from_root_to {
                 if predecessors.contains(&&edge.parent)
@@ -267,7 +988,14 @@ impl MutationGraph {
             )
             .map_err(MutationGraphError::FmtError)?;
         }
-        for weak_edge in self.weak_edge.values() {
+        let mut weak_edges: Vec<(NodeId, NodeId, LabelId)> = self
+            .weak_edge
+            .iter()
+            .map(|((parent, child), (label, ..))| (*parent, *child, *label))
+            .collect();
+        self.sort_edges(&mut weak_edges, &positions);
+        for (parent, child, label) in weak_edges {
+            let weak_edge = &self.edge_of(parent, child, label);
             let mut additional = String::new();
             if plot_options.highlight_edge_with_blue.contains(weak_edge) {
                 write!(&mut additional, ", color=\"blue\"")
@@ -298,11 +1026,17 @@ impl MutationGraph {
 mod test {
     use std::collections::HashSet;
     use std::iter::FromIterator;
+    use std::path::Path;
 
+    use crate::seed_tree::directed_edge::DirectedEdge;
     use crate::seed_tree::error::MutationGraphError;
     use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
     use crate::seed_tree::mutation_graph_node::MutationGraphNode;
+    use crate::seed_tree::node_id::NodeId;
     use crate::seed_tree::node_name::NodeName;
+    use crate::seed_tree::sort_order::SortOrder;
+    use crate::seed_tree::test_util::{graph_of, hash_of};
+    use crate::seed_tree::weak_edge::WeakEdgeReason;
     use crate::seed_tree::MutationGraph;
 
     impl MutationGraphEdge {
@@ -433,7 +1167,371 @@ mod test {
         graph.add_edge(&MutationGraphEdge::new(&node_1_sha1, &node_2_sha1));
         graph.add_edge(&MutationGraphEdge::new(&node_2_sha1, &node_3_sha1));
         graph.add_edge(&MutationGraphEdge::new(&node_3_sha1, &node_1_sha1));
+        graph.add_edge(&MutationGraphEdge::new(&node_2_sha1, &node_2_sha1));
 
         assert_eq!(graph.roots(), HashSet::from_iter(vec![&node_1_sha1]));
+
+        let weak_edges = graph.weak_edges();
+        assert_eq!(weak_edges.len(), 2);
+        assert_eq!(
+            weak_edges[0].edge,
+            MutationGraphEdge::new(&node_2_sha1, &node_2_sha1)
+        );
+        assert_eq!(weak_edges[0].reason, WeakEdgeReason::SelfLoop);
+        assert_eq!(weak_edges[0].path, vec![node_2_sha1.clone()]);
+        assert_eq!(
+            weak_edges[1].edge,
+            MutationGraphEdge::new(&node_3_sha1, &node_1_sha1)
+        );
+        assert_eq!(weak_edges[1].reason, WeakEdgeReason::ClosedChain);
+        assert_eq!(
+            weak_edges[1].path,
+            vec![
+                node_1_sha1.clone(),
+                node_2_sha1.clone(),
+                node_3_sha1.clone()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mutation_graph_reparenting() {
+        let node_1_sha1 = NodeName::from("node_1");
+        let node_2_sha1 = NodeName::from("node_2");
+        let node_3_sha1 = NodeName::from("node_3");
+
+        let mut graph = MutationGraph::new();
+        /*
+           (1) (2)
+             \ :
+              (3)
+        */
+        graph.add_edge(&MutationGraphEdge::new(&node_1_sha1, &node_3_sha1));
+        graph.add_edge(&MutationGraphEdge::new(&node_2_sha1, &node_3_sha1));
+
+        // Last parent wins
+        assert_eq!(graph.parent_of(&node_3_sha1), Some(&node_2_sha1));
+        assert_eq!(graph.edges().count(), 1);
+        let weak_edges = graph.weak_edges();
+        assert_eq!(weak_edges.len(), 1);
+        assert_eq!(
+            weak_edges[0].edge,
+            MutationGraphEdge::new(&node_1_sha1, &node_3_sha1)
+        );
+        assert_eq!(weak_edges[0].reason, WeakEdgeReason::Reparent);
+        assert_eq!(
+            weak_edges[0].path,
+            vec![node_2_sha1.clone(), node_3_sha1.clone()]
+        );
+        assert_eq!(
+            graph.roots(),
+            HashSet::from_iter(vec![&node_1_sha1, &node_2_sha1])
+        );
+        assert_eq!(
+            graph.leaves(),
+            HashSet::from_iter(vec![&node_1_sha1, &node_3_sha1])
+        );
+
+        /*
+           (1) (2)
+             : |
+              (3)
+               |
+              (4)
+        */
+        // A second parent in same tree is kept as weak edge
+        let node_4_sha1 = NodeName::from("node_4");
+        graph.add_edge(&MutationGraphEdge::new(&node_3_sha1, &node_4_sha1));
+        graph.add_edge(&MutationGraphEdge::new(&node_2_sha1, &node_4_sha1));
+        assert_eq!(graph.parent_of(&node_4_sha1), Some(&node_3_sha1));
+        assert_eq!(graph.weak_edges().len(), 2);
+        // Former tree of re-parented node is apart from its new tree
+        graph.add_edge(&MutationGraphEdge::new(&node_4_sha1, &node_1_sha1));
+        assert_eq!(graph.parent_of(&node_1_sha1), Some(&node_4_sha1));
+        assert_eq!(graph.root_of(&node_1_sha1).unwrap(), &node_2_sha1);
+    }
+
+    #[test]
+    fn test_mutation_graph_closed_chain_reparented() {
+        let (a, b, c, d) = (
+            NodeName::from("a"),
+            NodeName::from("b"),
+            NodeName::from("c"),
+            NodeName::from("d"),
+        );
+
+        let mut graph = MutationGraph::new();
+        /*
+           (a) <.
+            |   :
+           (b)  :     (d)
+            |   :
+           (c) .:
+        */
+        graph.add_edge(&MutationGraphEdge::new(&a, &b));
+        graph.add_edge(&MutationGraphEdge::new(&b, &c));
+        graph.add_edge(&MutationGraphEdge::new(&c, &a));
+        /*
+           (a) <.
+            :   :
+           (b)  : <-- (d)
+            |   :
+           (c) .:
+        */
+        graph.add_edge(&MutationGraphEdge::new(&d, &b));
+        assert_eq!(graph.parent_of(&b), Some(&d));
+
+        // Paths are of edges which caused weak edges, even after they are re-parented
+        let weak_edges = graph.weak_edges();
+        assert_eq!(weak_edges.len(), 2);
+        assert_eq!(weak_edges[0].edge, MutationGraphEdge::new(&a, &b));
+        assert_eq!(weak_edges[0].reason, WeakEdgeReason::Reparent);
+        assert_eq!(weak_edges[0].path, vec![d.clone(), b.clone()]);
+        assert_eq!(weak_edges[1].edge, MutationGraphEdge::new(&c, &a));
+        assert_eq!(weak_edges[1].reason, WeakEdgeReason::ClosedChain);
+        assert_eq!(weak_edges[1].path, vec![a.clone(), b.clone(), c.clone()]);
+    }
+
+    #[test]
+    fn test_mutation_graph_deep_lineage() {
+        const DEPTH: usize = 100000;
+        let names: Vec<NodeName> = (0..=DEPTH).map(|i| format!("{:06}", i)).collect();
+
+        let mut graph = MutationGraph::new();
+        for (parent, child) in names.iter().zip(names.iter().skip(1)) {
+            graph.add_edge(&MutationGraphEdge::new(parent, child));
+        }
+        // Closing chain from deepest node does not overflow stack either
+        graph.add_edge(&MutationGraphEdge::new(&names[DEPTH], &names[0]));
+
+        assert_eq!(graph.root_of(&names[DEPTH]), Ok(&names[0]));
+        assert_eq!(graph.rank_of(&names[DEPTH]), Ok(DEPTH));
+        let predecessors = graph.predecessors_of(&names[DEPTH]).unwrap();
+        assert_eq!(predecessors.len(), DEPTH);
+        assert_eq!(predecessors[0], &names[0]);
+        assert_eq!(predecessors[DEPTH - 1], &names[DEPTH - 1]);
+        assert_eq!(graph.roots(), HashSet::from_iter(vec![&names[0]]));
+    }
+
+    #[test]
+    fn test_mutation_graph_interning() {
+        let node_1 = NodeName::from("node_1");
+        let node_2 = NodeName::from("node_2");
+        let node_3 = NodeName::from("node_3");
+
+        let mut graph = MutationGraph::new();
+        // Edges declare missing nodes in order of appearance
+        graph.add_edge(&MutationGraphEdge {
+            parent: node_1.clone(),
+            child: node_2.clone(),
+            label: String::from("havoc"),
+        });
+        graph.add_weak_edge(&MutationGraphEdge {
+            parent: node_3.clone(),
+            child: node_2.clone(),
+            label: String::from("havoc"),
+        });
+        graph.add_node(&MutationGraphNode::new(&node_1));
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.label.len(), 1);
+        let ids: Vec<NodeId> = [&node_1, &node_2, &node_3]
+            .iter()
+            .map(|v| graph.id_of(v).unwrap())
+            .collect();
+        assert_eq!(ids, vec![NodeId::from(0), NodeId::from(1), NodeId::from(2)]);
+        assert_eq!(graph.name_of(ids[2]), &node_3);
+        assert_eq!(graph.id_of(&NodeName::from("no_such_node")), None);
+
+        assert_eq!(
+            graph.get_edge(&DirectedEdge::new(&node_1, &node_2)),
+            Some(MutationGraphEdge {
+                parent: node_1.clone(),
+                child: node_2.clone(),
+                label: String::from("havoc"),
+            })
+        );
+        assert_eq!(graph.get_edge(&DirectedEdge::new(&node_3, &node_2)), None);
+        assert_eq!(graph.children_of(&node_1), Some(vec![&node_2]));
+        assert_eq!(graph.children_of(&node_3), Some(vec![]));
+    }
+
+    #[test]
+    fn test_mutation_graph_sort_order() {
+        let a = NodeName::from("a");
+        let b = NodeName::from("b");
+        let c = NodeName::from("c");
+        let d = NodeName::from("d");
+
+        let mut graph = MutationGraph::new();
+        /*
+           (d)   (b)
+            |
+           (c)
+            |
+           (a)
+        */
+        graph.add_edge(&MutationGraphEdge::new(&d, &c));
+        graph.add_edge(&MutationGraphEdge::new(&c, &a));
+        graph.add_node(&MutationGraphNode {
+            discovered: Some(10),
+            ..MutationGraphNode::new(&a)
+        });
+        graph.add_node(&MutationGraphNode {
+            discovered: Some(20),
+            ..MutationGraphNode::new(&b)
+        });
+        graph.add_node(&MutationGraphNode {
+            discovered: Some(30),
+            ..MutationGraphNode::new(&c)
+        });
+
+        let names = |order| -> Vec<NodeName> {
+            graph
+                .sorted_nodes(order)
+                .iter()
+                .map(|v| v.name.clone())
+                .collect()
+        };
+        assert_eq!(names(SortOrder::Name), vec!["a", "b", "c", "d"]);
+        assert_eq!(names(SortOrder::Id), vec!["d", "c", "a", "b"]);
+        assert_eq!(names(SortOrder::Discovery), vec!["d", "a", "b", "c"]);
+        assert_eq!(names(SortOrder::Topological), vec!["b", "d", "c", "a"]);
+
+        let no_such_node = NodeName::from("0");
+        assert_eq!(
+            graph.sort_names(vec![&a, &no_such_node, &c], SortOrder::Topological),
+            vec![&c, &a, &no_such_node]
+        );
+        assert_eq!(
+            graph.sorted_edges(SortOrder::Name),
+            vec![
+                MutationGraphEdge::new(&c, &a),
+                MutationGraphEdge::new(&d, &c)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mutation_graph_duplicates() {
+        let node_of = |name: &str, data: &str| {
+            MutationGraphNode::new_with_metadata(
+                &NodeName::from(name),
+                false,
+                Path::new(""),
+                &hash_of(data),
+            )
+        };
+        let (x, y, z) = (hash_of("x"), hash_of("y"), hash_of("z"));
+
+        let mut graph = MutationGraph::new();
+        graph.add_node(&MutationGraphNode {
+            initial_seed: true,
+            ..node_of("seed", "x")
+        });
+        graph.add_node(&node_of("a", "x"));
+        graph.add_node(&node_of("b", "y"));
+        graph.add_node(&node_of("c", "x"));
+
+        assert_eq!(graph.nodes_with_hash(&x), vec!["seed", "a", "c"]);
+        assert_eq!(graph.nodes_with_hash(&z), Vec::<&NodeName>::new());
+        // Initial seed is not representative of contents copied to queue
+        assert_eq!(graph.lookup_by_file_hash(&x), Ok(&NodeName::from("a")));
+        assert_eq!(graph.duplicates(), vec![vec!["seed", "a", "c"]]);
+
+        // Re-declared nodes move to their new contents
+        graph.add_node(&node_of("c", "z"));
+        graph.add_node(&node_of("b", ""));
+        assert_eq!(graph.nodes_with_hash(&x), vec!["seed", "a"]);
+        assert_eq!(graph.nodes_with_hash(&z), vec!["c"]);
+        assert_eq!(
+            graph.lookup_by_file_hash(&y),
+            Err(MutationGraphError::FileHashNotExists(y.clone()))
+        );
+    }
+
+    #[test]
+    fn test_mutation_graph_lookup_by_file_hash_prefix() {
+        let mut graph = MutationGraph::new();
+        // SHA-1 of "x", "z" and "k" are 11f6ad.., 395df8.. and 13fbd7..
+        for (name, data) in [("a", "x"), ("b", "z"), ("c", "k")].iter() {
+            graph.add_node(&MutationGraphNode::new_with_metadata(
+                &NodeName::from(*name),
+                false,
+                Path::new(""),
+                &hash_of(data),
+            ));
+        }
+
+        assert_eq!(
+            graph.lookup_by_file_hash_prefix("11F6"),
+            Ok(&NodeName::from("a"))
+        );
+        assert_eq!(
+            graph.lookup_by_file_hash_prefix(&hash_of("z").to_string()),
+            Ok(&NodeName::from("b"))
+        );
+        assert_eq!(
+            graph.lookup_by_file_hash_prefix("1"),
+            Err(MutationGraphError::AmbiguousFileHash(
+                String::from("1"),
+                vec![hash_of("x"), hash_of("k")]
+            ))
+        );
+        for prefix in ["95cb", "", "xyz"].iter() {
+            assert_eq!(
+                graph.lookup_by_file_hash_prefix(prefix),
+                Err(MutationGraphError::FileHashPrefixNotExists(
+                    prefix.to_string()
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn test_mutation_graph_merge() {
+        let libfuzzer = graph_of(&[("aa", "aa", false), ("bb", "bb", false)], &[("aa", "bb")]);
+        let afl = graph_of(
+            &[
+                ("000000", "aa", false),
+                ("000001", "bb", true),
+                ("000002", "cc", false),
+            ],
+            &[("000000", "000001"), ("000000", "000002")],
+        );
+        // Another campaign whose ids collide with the one above
+        let other_afl = graph_of(&[("000000", "dd", false)], &[]);
+
+        let mut graph = MutationGraph::new();
+        graph.merge(&libfuzzer, "libfuzzer");
+        graph.merge(&afl, "afl");
+        graph.merge(&other_afl, "other");
+
+        let names: Vec<&NodeName> = graph.nodes().map(|v| &v.name).collect();
+        assert_eq!(names, vec!["aa", "bb", "000002", "other:000000"]);
+        // Id of AFL's node unified with "aa" is not taken by the other campaign
+        assert!(graph.get_node(&NodeName::from("000000")).is_none());
+        assert!(graph.get_node(&NodeName::from("bb")).unwrap().crashed);
+        assert_eq!(
+            graph.parent_of(&NodeName::from("000002")),
+            Some(&NodeName::from("aa"))
+        );
+        assert!(graph.weak_edges().is_empty());
+
+        assert_eq!(graph.sources(), ["libfuzzer", "afl", "other"]);
+        assert_eq!(
+            graph.sources_of(&NodeName::from("aa")),
+            vec!["libfuzzer", "afl"]
+        );
+        assert_eq!(graph.sources_of(&NodeName::from("000002")), vec!["afl"]);
+        assert_eq!(
+            graph.sources_of(&NodeName::from("other:000000")),
+            vec!["other"]
+        );
+
+        let bb = NodeName::from("bb");
+        let subgraph = graph.subgraph(&HashSet::from_iter(vec![&bb]));
+        assert_eq!(subgraph.sources(), graph.sources());
+        assert_eq!(subgraph.sources_of(&bb), vec!["libfuzzer", "afl"]);
     }
 }
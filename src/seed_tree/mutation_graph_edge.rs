@@ -24,10 +24,6 @@ impl PartialOrd for MutationGraphEdge {
 
 impl Ord for MutationGraphEdge {
     fn cmp(&self, other: &Self) -> Ordering {
-        if self.parent.cmp(&other.parent).is_eq() {
-            self.child.cmp(&other.child)
-        } else {
-            Ordering::Equal
-        }
+        (&self.parent, &self.child).cmp(&(&other.parent, &other.child))
     }
 }
@@ -1,3 +1,4 @@
+use super::discovery_stats::DiscoveryStats;
 use super::file_hash::FileHash;
 use super::node_name::NodeName;
 use std::cmp::Ordering;
@@ -9,6 +10,12 @@ pub struct MutationGraphNode {
     pub crashed: bool,
     pub file: PathBuf,
     pub hash: FileHash,
+    /// Time when the fuzzer found this input (milliseconds since start of fuzzing) if known
+    pub discovered: Option<u64>,
+    /// Status of libFuzzer at discovery of this input if known (see `--log`)
+    pub stats: Option<DiscoveryStats>,
+    /// Whether this node is an initial seed given to the fuzzer (see `--input-corpus`)
+    pub initial_seed: bool,
 }
 
 impl PartialEq for MutationGraphNode {
@@ -45,6 +52,30 @@ impl MutationGraphNode {
             crashed,
             file: file.to_path_buf(),
             hash: hash.clone(),
+            discovered: None,
+            stats: None,
+            initial_seed: false,
+        }
+    }
+
+    /// Merges metadata of `other` node with same contents into this node. Metadata of this node precedes.
+    pub fn merged_with(&self, other: &MutationGraphNode) -> Self {
+        Self {
+            name: self.name.clone(),
+            crashed: self.crashed || other.crashed,
+            file: if self.file.as_os_str().is_empty() {
+                other.file.clone()
+            } else {
+                self.file.clone()
+            },
+            hash: if self.hash.is_empty() {
+                other.hash.clone()
+            } else {
+                self.hash.clone()
+            },
+            discovered: self.discovered.or(other.discovered),
+            stats: self.stats.or(other.stats),
+            initial_seed: self.initial_seed || other.initial_seed,
         }
     }
 }
@@ -0,0 +1,66 @@
+use std::convert::TryFrom;
+
+/// Index of a node interned in `MutationGraph`. Ids are assigned in order of node declaration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(u32);
+
+impl NodeId {
+    pub fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl From<usize> for NodeId {
+    fn from(index: usize) -> Self {
+        // NOTE: 2^32 nodes are far beyond any real campaign
+        Self(u32::try_from(index).expect("Too many nodes"))
+    }
+}
+
+/// Index of an interned edge label
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LabelId(u32);
+
+impl LabelId {
+    pub fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl From<usize> for LabelId {
+    fn from(index: usize) -> Self {
+        Self(u32::try_from(index).expect("Too many labels"))
+    }
+}
+
+/// Index of an interned job (e.g. a mutation graph file of libFuzzer's fork mode) which edges come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct JobId(u32);
+
+impl JobId {
+    pub fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl From<usize> for JobId {
+    fn from(index: usize) -> Self {
+        Self(u32::try_from(index).expect("Too many jobs"))
+    }
+}
+
+/// Index of an interned source (e.g. a seed tree merged by `MutationGraph::merge()`) which nodes come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SourceId(u32);
+
+impl SourceId {
+    pub fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl From<usize> for SourceId {
+    fn from(index: usize) -> Self {
+        Self(u32::try_from(index).expect("Too many sources"))
+    }
+}
@@ -1,16 +1,26 @@
+use super::afl_stats::AFL_METADATA_FILES;
 use super::error::ParseError;
 use super::result::Result;
+use crate::seed_tree::corpus::list_files;
+use crate::seed_tree::hash_cache::{CachedNode, FileHashCache};
 use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
 use crate::seed_tree::mutation_graph_node::MutationGraphNode;
-use crate::seed_tree::util::calc_file_hash;
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::util::{available_jobs, parallel_map};
 use crate::seed_tree::MutationGraph;
 
 use regex::Regex;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::ffi::OsStr;
 use std::path::Path;
 use std::path::PathBuf;
 
+/// Extensions of AFL changing how input files are read.
+///
+/// With `aurora`, inputs are read as output of crash exploration mode of [AURORA](https://github.com/RUB-SysSec/aurora):
+/// inputs in `queue/` and `crashes/` and initial seeds are crashing inputs (i.e. crashed nodes), and inputs in `non_crashes/`
+/// named with `_<id>` suffix are non-crashing inputs named `nc-<id>`. Inputs in `crashes/` are named `crash-<id>`
+/// since they have ids apart from `queue/`.
 #[derive(Debug)]
 pub struct AFLExtensions {
     pub(crate) aurora: bool,
@@ -25,83 +35,170 @@ impl AFLExtensions {
     pub fn aurora(&self) -> bool {
         self.aurora
     }
+
+    // Key of nodes in hash cache, since extensions change how file names are parsed
+    fn cache_key(&self) -> String {
+        format!(
+            "afl:aurora={}:crashes={:?}",
+            self.aurora, self.crash_inputs_dir
+        )
+    }
 }
 
 pub fn parse_afl_input_directories<T: AsRef<Path>>(
     directories: HashSet<T>,
     extensions: &AFLExtensions,
 ) -> Result<MutationGraph> {
-    let mut res = MutationGraph::new();
+    parse_afl_input_directories_with_cache(
+        directories,
+        extensions,
+        &mut FileHashCache::new(),
+        available_jobs(),
+    )
+}
+
+/// Same as `parse_afl_input_directories()`, but looks up file hashes from `cache` before calculating them.
+/// Input files are parsed and hashed by `jobs` worker threads.
+pub fn parse_afl_input_directories_with_cache<T: AsRef<Path>>(
+    directories: HashSet<T>,
+    extensions: &AFLExtensions,
+    cache: &mut FileHashCache,
+    jobs: usize,
+) -> Result<MutationGraph> {
+    let mut directories: Vec<&Path> = directories.iter().map(|v| v.as_ref()).collect();
+    directories.sort();
+
+    let mut files = Vec::new();
     for directory in directories {
-        parse_afl_input_directory(directory, &mut res, extensions)?;
+        enumerate_input_files(directory, &mut files)?;
     }
+
+    let mut res = MutationGraph::new();
+    add_input_files(files, &mut res, extensions, cache, jobs)?;
     Ok(res)
 }
 
-fn parse_afl_input_directory<T: AsRef<Path>>(
-    directory: T,
-    graph: &mut MutationGraph,
-    extensions: &AFLExtensions,
-) -> Result<()> {
-    if directory.as_ref().is_file() {
-        return Err(ParseError::UnexpectedFilePath(
-            directory.as_ref().to_path_buf(),
-        ));
+// Lists files under `directory` without recursion to avoid stack overflow on deep directories
+fn enumerate_input_files(directory: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    if directory.is_file() {
+        return Err(ParseError::UnexpectedFilePath(directory.to_path_buf()));
     }
 
-    visit_directory(directory.as_ref().to_path_buf(), graph, extensions)?;
+    let mut directories = vec![directory.to_path_buf()];
+    while let Some(directory) = directories.pop() {
+        log::trace!("Scanning directory {:?}", directory);
+        let entries = directory
+            .read_dir()
+            .map_err(|why| ParseError::from(why).in_file(&directory))?;
+        for entry in entries {
+            let file_path = entry
+                .map_err(|why| ParseError::from(why).in_file(&directory))?
+                .path();
+            if file_path.is_dir() {
+                if file_path.file_name() == Some(OsStr::new(".state")) {
+                    log::warn!("Skipped directory {:?}", file_path);
+                } else {
+                    directories.push(file_path);
+                }
+            } else {
+                files.push(file_path);
+            }
+        }
+    }
 
     Ok(())
 }
 
-fn visit_directory(
-    directory: PathBuf,
-    graph: &mut MutationGraph,
-    extensions: &AFLExtensions,
-) -> Result<()> {
-    log::trace!("Scanning directory {:?}", directory);
-
-    let one_line_info = Regex::new(if extensions.aurora() {
-        "^id:(\\d+)(?:,sig:\\d+)?,(?:src|orig):([^:]+)(?:,op:([^_]+)(?:_(\\S+))?)?$"
-        //   ~~~~~~1                           ~~~~~~~2      ~~~~~~~3 ~~~~~~4
-    } else {
-        "^id:(\\d+)(?:,sig:\\d+)?(?:,time:\\d+)?(?:,execs:\\d+)?,(?:src|orig):([^:]+)(?:,time:\\d+)?(?:,execs:\\d+)?(?:,op:(\\S+))?$"
-        //   ~~~~~~1                                                          ~~~~~~~2                                     ~~~~~~3
-    })?;
-
-    for entry in directory.read_dir()? {
-        let file_path = entry?.path();
-
-        // Recursively iterate directory
-        if file_path.is_dir() {
-            if file_path.file_name() == Some(OsStr::new(".state")) {
-                log::warn!("Skipped directory {:?}", file_path);
-            } else {
-                visit_directory(file_path, graph, extensions)?;
-            }
-            continue;
+#[derive(Debug)]
+enum InputFile {
+    // An input file named in AFL's format; `src` is the first source input of splice
+    Input {
+        id: NodeName,
+        crashed: bool,
+        src: NodeName,
+        op: String,
+        discovered: Option<u64>,
+    },
+    // A file does not have AFL's input file name format (e.g. initial seeds)
+    Seed {
+        name: NodeName,
+    },
+    Ignored,
+}
+
+impl InputFile {
+    fn into_node(self, extensions: &AFLExtensions) -> Option<CachedNode> {
+        match self {
+            InputFile::Input {
+                id,
+                crashed,
+                src,
+                op,
+                discovered,
+            } => Some(CachedNode {
+                name: id,
+                crashed,
+                discovered,
+                parent: Some((src, op)),
+            }),
+            // NOTE: Crash exploration starts from crashing inputs
+            InputFile::Seed { name } => Some(CachedNode {
+                name,
+                crashed: extensions.aurora(),
+                discovered: None,
+                parent: None,
+            }),
+            InputFile::Ignored => None,
         }
+    }
+}
+
+struct InputFileNameParser<'a> {
+    one_line_info: Regex,
+    extensions: &'a AFLExtensions,
+}
 
+impl<'a> InputFileNameParser<'a> {
+    fn new(extensions: &'a AFLExtensions) -> Result<Self> {
+        let one_line_info = Regex::new(if extensions.aurora() {
+            "^id:(\\d+)(?:,sig:\\d+)?,(?:src|orig):([^:]+)(?:,op:([^_]+)(?:_(\\S+))?)?$"
+            //   ~~~~~~1                           ~~~~~~~2      ~~~~~~~3 ~~~~~~4
+        } else {
+            "^id:(\\d+)(?:,sig:\\d+)?(?:,time:(\\d+))?(?:,execs:\\d+)?,(?:src|orig):([^:]+)(?:,time:(\\d+))?(?:,execs:\\d+)?(?:,op:(\\S+))?$"
+            //   ~~~~~~1                     ~~~~~~2                        ~~~~~~~3         ~~~~~~4                      ~~~~~~5
+        })?;
+        Ok(Self {
+            one_line_info,
+            extensions,
+        })
+    }
+
+    fn parse(&self, file_path: &Path) -> Result<InputFile> {
         // log::trace!("parsing file name: {}", file_name);
         let file_name = match file_path.file_name() {
             Some(file_name) => file_name.to_str().ok_or(ParseError::StringEncoding)?,
-            None => return Err(ParseError::UnexpectedFilePath(file_path)),
+            None => return Err(ParseError::UnexpectedFilePath(file_path.to_path_buf())),
         };
 
-        let is_crash_input_node = match extensions.crash_inputs_dir {
-            Some(ref crash_input_dir) => &directory == crash_input_dir,
-            None => directory.ends_with("crashes"),
+        let is_crash_input_node = match (
+            self.extensions.crash_inputs_dir.as_ref(),
+            file_path.parent(),
+        ) {
+            (Some(crash_input_dir), Some(directory)) => directory == crash_input_dir,
+            (None, Some(directory)) => directory.ends_with("crashes"),
+            (_, None) => false,
         };
 
-        match one_line_info.captures(file_name) {
+        match self.one_line_info.captures(file_name) {
             Some(captures) => {
                 let id = match captures.get(1) {
                     Some(id) => {
-                        if extensions.aurora() {
+                        if self.extensions.aurora() {
                             match captures.get(4) {
                                 Some(non_crash_id) => {
                                     format!("nc-{}", non_crash_id.as_str())
                                 }
+                                None if is_crash_input_node => format!("crash-{}", id.as_str()),
                                 None => id.as_str().to_string(),
                             }
                         } else {
@@ -119,15 +216,21 @@ fn visit_directory(
                         ))
                     }
                 };
-                graph.add_node(&MutationGraphNode::new_with_metadata(
-                    &id.to_string(),
-                    is_crash_input_node,
-                    file_path.as_path(),
-                    &calc_file_hash(file_path.as_path())?,
-                ));
-
-                let src_list = match captures.get(2) {
-                    Some(src_list) => src_list.as_str().split("+"),
+
+                let (src, op, discovered) = if self.extensions.aurora() {
+                    (captures.get(2), captures.get(3), None)
+                } else {
+                    (
+                        captures.get(3),
+                        captures.get(5),
+                        // NOTE: Initial seeds have time before orig, other inputs have it after src
+                        captures.get(2).or_else(|| captures.get(4)),
+                    )
+                };
+
+                let src = match src {
+                    // Ignore splice source input
+                    Some(src_list) => src_list.as_str().split("+").next().unwrap_or_default(),
                     None => {
                         return Err(ParseError::SyntaxError(
                             "'src' does not exists",
@@ -135,51 +238,191 @@ fn visit_directory(
                         ))
                     }
                 };
-                let op = match captures.get(3) {
+                let op = match op {
                     Some(op) => op.as_str(),
                     None => "origin",
                 };
+                let discovered = match discovered {
+                    Some(time) => Some(time.as_str().parse::<u64>().map_err(|_| {
+                        ParseError::SyntaxError("'time' is out of range", file_name.to_string())
+                    })?),
+                    None => None,
+                };
 
-                for src in src_list {
-                    graph.add_edge(&MutationGraphEdge {
-                        parent: src.to_string(),
-                        child: id.to_string(),
-                        label: op.to_string(),
-                    });
-                    break; // Ignore splice source input
-                }
+                // NOTE: Every input except non-crashing ones crashes in crash exploration mode
+                let crashed = if self.extensions.aurora() {
+                    captures.get(4).is_none()
+                } else {
+                    is_crash_input_node
+                };
+
+                Ok(InputFile::Input {
+                    id,
+                    crashed,
+                    src: src.to_string(),
+                    op: op.to_string(),
+                    discovered,
+                })
             }
             None => {
                 if file_name.starts_with("id:") {
                     log::warn!(
                         "file \"{}\" does not have AFL's input file name format",
                         file_name
-                    )
+                    );
+                    Ok(InputFile::Ignored)
                 } else {
                     if file_name == "README.txt" {
-                        log::info!("README file \"{}\" found. Skip", file_name)
+                        log::info!("README file \"{}\" found. Skip", file_name);
+                        Ok(InputFile::Ignored)
+                    } else if AFL_METADATA_FILES.contains(&file_name) {
+                        log::info!("AFL's status file \"{}\" found. Skip", file_name);
+                        Ok(InputFile::Ignored)
                     } else {
-                        graph.add_node(&MutationGraphNode::new_with_metadata(
-                            &file_name.to_string(),
-                            false,
-                            &file_path,
-                            &calc_file_hash(file_path.as_path())?,
-                        ))
+                        Ok(InputFile::Seed {
+                            name: file_name.to_string(),
+                        })
                     }
                 }
             }
         }
     }
+}
+
+fn add_input_files(
+    mut files: Vec<PathBuf>,
+    graph: &mut MutationGraph,
+    extensions: &AFLExtensions,
+    cache: &mut FileHashCache,
+    jobs: usize,
+) -> Result<()> {
+    // NOTE: Sort files to build same graph regardless of order of directory entries
+    files.sort();
+    files.dedup();
+    log::info!("Found {} files", files.len());
+
+    // NOTE: Only files not in cache are parsed and hashed
+    let cache_key = extensions.cache_key();
+    let mut nodes = cache.lookup_nodes(&files, &cache_key, jobs);
+    let misses: Vec<usize> = (0..files.len()).filter(|&i| nodes[i].is_none()).collect();
+    log::debug!("Parsing {} files not in cache", misses.len());
+
+    let parser = InputFileNameParser::new(extensions)?;
+    let input_files = parallel_map(&misses, jobs, |&i| {
+        parser
+            .parse(&files[i])
+            .map_err(|why| why.in_file(&files[i]))
+    })
+    .into_iter()
+    .collect::<Result<Vec<InputFile>>>()?;
+
+    let parsed: Vec<(usize, CachedNode)> = misses
+        .into_iter()
+        .zip(input_files)
+        .filter_map(|(i, input_file)| Some((i, input_file.into_node(extensions)?)))
+        .collect();
+    let hashes = {
+        let paths: Vec<&PathBuf> = parsed.iter().map(|(i, _)| &files[*i]).collect();
+        cache.calc_file_hashes(&paths, jobs)
+    };
+    for ((i, node), hash) in parsed.into_iter().zip(hashes) {
+        let hash = hash.map_err(|why| ParseError::from(why).in_file(&files[i]))?;
+        cache.insert_node(&files[i], &cache_key, node.clone());
+        nodes[i] = Some((node, hash));
+    }
+
+    for (file_path, node) in files.iter().zip(nodes) {
+        let (node, hash) = match node {
+            Some(node) => node,
+            None => continue,
+        };
+        let mut graph_node =
+            MutationGraphNode::new_with_metadata(&node.name, node.crashed, file_path, &hash);
+        graph_node.discovered = node.discovered;
+        graph.add_node(&graph_node);
+        if let Some((parent, label)) = node.parent {
+            graph.add_edge(&MutationGraphEdge {
+                parent,
+                child: node.name,
+                label,
+            });
+        }
+    }
 
     Ok(())
 }
 
+/// Resolves nodes named by `orig:` of AFL's initial inputs (e.g. `hello.pdf` of `id:000000,orig:hello.pdf`)
+/// to files in input corpus `directories` (i.e. `-i` of afl-fuzz), and marks them as initial seeds.
+/// Files are looked up by their names, since AFL records only file names of initial seeds. Returns number of resolved nodes.
+pub fn link_afl_input_corpus<P: AsRef<Path>>(
+    directories: &[P],
+    graph: &mut MutationGraph,
+    cache: &mut FileHashCache,
+    jobs: usize,
+) -> Result<usize> {
+    let mut files = Vec::new();
+    for directory in directories.iter() {
+        let directory = directory.as_ref();
+        if !directory.is_dir() {
+            return Err(ParseError::UnexpectedFilePath(directory.to_path_buf()));
+        }
+        list_files(directory, &mut files)?;
+    }
+    // NOTE: First path in order of name is kept for files with same name in different directories
+    files.sort();
+
+    let mut file_of: HashMap<NodeName, &PathBuf> = HashMap::new();
+    for file in files.iter() {
+        let name = match file.file_name().and_then(OsStr::to_str) {
+            Some(name) => name.to_string(),
+            None => return Err(ParseError::StringEncoding.in_file(file)),
+        };
+        if let Some(other) = file_of.get(&name) {
+            log::warn!("Initial seed {:?} is shadowed by {:?}", file, other);
+            continue;
+        }
+        file_of.insert(name, file);
+    }
+
+    // NOTE: Nodes with files are inputs found by AFL, even if their names collide with initial seeds
+    let seeds: Vec<(NodeName, &PathBuf)> = graph
+        .nodes()
+        .filter(|node| node.file.as_os_str().is_empty())
+        .filter_map(|node| Some((node.name.clone(), *file_of.get(&node.name)?)))
+        .collect();
+    let hashes = {
+        let paths: Vec<&PathBuf> = seeds.iter().map(|(_, file)| *file).collect();
+        cache.calc_file_hashes(&paths, jobs)
+    };
+    for ((name, file), hash) in seeds.iter().zip(hashes) {
+        let hash = hash.map_err(|why| ParseError::from(why).in_file(file))?;
+        let mut node = graph.get_node(name).expect("Node listed above").clone();
+        node.file = file.to_path_buf();
+        node.hash = hash;
+        node.initial_seed = true;
+        graph.add_node(&node);
+    }
+    if file_of.len() > seeds.len() {
+        log::info!(
+            "{} of {} files in input corpus are not referred by orig: of inputs",
+            file_of.len() - seeds.len(),
+            file_of.len()
+        );
+    }
+    Ok(seeds.len())
+}
+
 #[cfg(test)]
 mod test {
+    use crate::seed_tree::file_hash::FileHash;
+    use crate::seed_tree::hash_algorithm::HashAlgorithm;
+    use crate::seed_tree::hash_cache::FileHashCache;
     use crate::seed_tree::mutation_graph_node::MutationGraphNode;
     use crate::seed_tree::node_name::NodeName;
-    use crate::seed_tree::parser::afl::{parse_afl_input_directory, AFLExtensions};
-    use crate::seed_tree::MutationGraph;
+    use crate::seed_tree::parser::afl::{
+        link_afl_input_corpus, parse_afl_input_directories_with_cache, AFLExtensions,
+    };
     use std::collections::HashSet;
     use std::iter::FromIterator;
     use std::path::Path;
@@ -194,16 +437,16 @@ mod test {
     fn test_aflplusplus_seed_tree() {
         let seed_dir = Path::new("test/sample/seed-tree/aflplusplus-4.05c/");
 
-        let mut graph = MutationGraph::new();
-        assert!(parse_afl_input_directory(
-            seed_dir,
-            &mut graph,
+        let graph = parse_afl_input_directories_with_cache(
+            HashSet::from_iter([seed_dir]),
             &AFLExtensions {
                 aurora: false,
-                crash_inputs_dir: Some(seed_dir.join("crashes/"))
-            }
+                crash_inputs_dir: Some(seed_dir.join("crashes/")),
+            },
+            &mut FileHashCache::new(),
+            2,
         )
-        .is_ok());
+        .unwrap();
 
         // println!("{:#?}", graph);
 
@@ -213,7 +456,14 @@ mod test {
                     name: String::from("000000"),
                     crashed: false,
                     file: seed_dir.join("queue/id:000000,time:0,execs:0,orig:hello.attach-123.pdf"),
-                    hash: String::from("da39a3ee5e6b4b0d3255bfef95601890afd80709"),
+                    hash: FileHash::from_hex(
+                        HashAlgorithm::Sha1,
+                        "da39a3ee5e6b4b0d3255bfef95601890afd80709",
+                    )
+                    .unwrap(),
+                    discovered: Some(0),
+                    stats: None,
+                    initial_seed: false,
                 };
                 assert_eq!(node, &expected);
                 assert_eq!(node.crashed, expected.crashed);
@@ -225,31 +475,67 @@ mod test {
 
         match graph.get_node(&String::from("crash-000002")) {
             Some(node) => {
-                let expected = MutationGraphNode { name: String::from("crash-000002"), crashed: true, file: seed_dir.join("crashes/id:000002,sig:06,src:000000,time:8024,execs:2409,op:colorization,pos:0"), hash: String::from("7e240de74fb1ed08fa08d38063f6a6a91462a815") };
+                let expected = MutationGraphNode { name: String::from("crash-000002"), crashed: true, file: seed_dir.join("crashes/id:000002,sig:06,src:000000,time:8024,execs:2409,op:colorization,pos:0"), hash: FileHash::from_hex(HashAlgorithm::Sha1, "7e240de74fb1ed08fa08d38063f6a6a91462a815").unwrap(), discovered: Some(8024), stats: None, initial_seed: false };
                 assert_eq!(node, &expected);
                 assert_eq!(node.crashed, expected.crashed);
                 assert_eq!(node.file, expected.file);
                 assert_eq!(node.hash, expected.hash);
+                assert_eq!(node.discovered, expected.discovered);
             }
             None => unreachable!(),
         }
     }
 
+    #[test]
+    fn test_link_afl_input_corpus() {
+        let seed_dir = Path::new("test/sample/seed-tree/aflplusplus-4.05c/");
+        let mut graph = parse_afl_input_directories_with_cache(
+            HashSet::from_iter([seed_dir]),
+            &AFLExtensions {
+                aurora: false,
+                crash_inputs_dir: Some(seed_dir.join("crashes/")),
+            },
+            &mut FileHashCache::new(),
+            2,
+        )
+        .unwrap();
+
+        let input_corpus = std::env::temp_dir().join(format!(
+            "seed-tree-analyzer-test-input-corpus-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(input_corpus.join("unused")).unwrap();
+        std::fs::write(input_corpus.join("hello.attach-123.pdf"), "").unwrap();
+        std::fs::write(input_corpus.join("unused/world.pdf"), "world").unwrap();
+        let linked =
+            link_afl_input_corpus(&[&input_corpus], &mut graph, &mut FileHashCache::new(), 1);
+        let _ = std::fs::remove_dir_all(&input_corpus);
+
+        assert_eq!(linked.unwrap(), 1);
+        let seed = graph.get_node(node!("hello.attach-123.pdf")).unwrap();
+        assert!(seed.initial_seed);
+        assert_eq!(seed.file, input_corpus.join("hello.attach-123.pdf"));
+        assert_eq!(seed.hash, FileHash::of(HashAlgorithm::Sha1, b""));
+        assert!(!graph.get_node(node!("000000")).unwrap().initial_seed);
+        // Copy of initial seed in queue is still found by hash
+        assert_eq!(graph.lookup_by_file_hash(&seed.hash), Ok(node!("000000")));
+    }
+
     #[test]
     fn test_aurora_seed_tree() {
-        let mut graph = MutationGraph::new();
-        assert!(parse_afl_input_directory(
-            "test/sample/seed-tree/afl-aurora-crash-exploration/",
-            &mut graph,
+        let graph = parse_afl_input_directories_with_cache(
+            HashSet::from_iter(["test/sample/seed-tree/afl-aurora-crash-exploration/"]),
             &AFLExtensions {
                 aurora: true,
                 crash_inputs_dir: Some(
                     Path::new("test/sample/seed-tree/afl-aurora-crash-exploration/crashes/")
-                        .to_path_buf()
-                )
-            }
+                        .to_path_buf(),
+                ),
+            },
+            &mut FileHashCache::new(),
+            2,
         )
-        .is_ok());
+        .unwrap();
 
         assert_eq!(
             graph.roots(),
@@ -268,11 +554,34 @@ mod test {
                 node!("000012"),
                 node!("000013"),
                 node!("000014"),
+                node!("crash-000000"),
+                node!("crash-000001"),
+                node!("crash-000002"),
+                node!("crash-000003"),
+                node!("crash-000004"),
+                node!("crash-000005"),
+                node!("crash-000006"),
+                node!("crash-000007"),
+                node!("crash-000008"),
                 node!("nc-143"),
                 node!("nc-228"),
                 node!("nc-298"),
                 node!("nc-348"),
             ])
         );
+        assert_eq!(
+            graph.parent_of(node!("crash-000008")),
+            Some(node!("000007"))
+        );
+        for name in [
+            "crash-40fc056ab481fe4adb78715ea20a0fa486c81ec9",
+            "000007",
+            "crash-000008",
+        ]
+        .iter()
+        {
+            assert!(graph.get_node(node!(*name)).unwrap().crashed);
+        }
+        assert!(!graph.get_node(node!("nc-143")).unwrap().crashed);
     }
 }
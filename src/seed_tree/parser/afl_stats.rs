@@ -0,0 +1,351 @@
+use super::error::ParseError;
+use super::result::Result;
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Files written by AFL/AFL++ next to `queue/`, which are not inputs
+pub const AFL_METADATA_FILES: &[&str] = &[
+    "fuzzer_stats",
+    "plot_data",
+    "fuzzer_setup",
+    "cmdline",
+    "fuzz_bitmap",
+    "target_hash",
+    ".cur_input",
+    ".synced",
+];
+
+// Keys renamed by AFL++, which are looked up when current key is missing
+const FUZZER_STATS_ALIASES: &[(&str, &str)] = &[
+    ("corpus_count", "paths_total"),
+    ("saved_crashes", "unique_crashes"),
+    ("saved_hangs", "unique_hangs"),
+];
+
+/// Contents of `fuzzer_stats`, i.e. lines of `key : value`
+#[derive(Debug, Clone, Default)]
+pub struct FuzzerStats {
+    values: BTreeMap<String, String>,
+}
+
+impl FuzzerStats {
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values
+            .get(key)
+            .or_else(|| {
+                FUZZER_STATS_ALIASES
+                    .iter()
+                    .find(|(current, _)| *current == key)
+                    .and_then(|(_, old)| self.values.get(*old))
+            })
+            .map(|v| v.as_str())
+    }
+
+    pub fn get_u64(&self, key: &str) -> Option<u64> {
+        self.get(key)?.parse().ok()
+    }
+
+    /// Value as number dropping unit (e.g. `12.34%` of `bitmap_cvg`)
+    pub fn get_f64(&self, key: &str) -> Option<f64> {
+        self.get(key)?.trim_end_matches('%').parse().ok()
+    }
+
+    /// Name of instance given by `-M`/`-S` or `-T`
+    pub fn banner(&self) -> Option<&str> {
+        self.get("afl_banner")
+    }
+
+    /// Seconds from start to last update
+    pub fn run_time(&self) -> Option<u64> {
+        self.get_u64("run_time").or_else(|| {
+            self.get_u64("last_update")?
+                .checked_sub(self.get_u64("start_time")?)
+        })
+    }
+}
+
+pub fn parse_fuzzer_stats_file<T: AsRef<Path>>(file: T) -> Result<FuzzerStats> {
+    let file = file.as_ref();
+    let reader = File::open(file).map_err(|why| ParseError::from(why).in_file(file))?;
+    parse_fuzzer_stats(reader).map_err(|why| why.in_file(file))
+}
+
+pub fn parse_fuzzer_stats<T: Read>(reader: T) -> Result<FuzzerStats> {
+    let mut values = BTreeMap::new();
+    for (index, line) in BufReader::new(reader).lines().enumerate() {
+        let line = line.map_err(|why| ParseError::from(why).at_line(index + 1))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match line.split_once(':') {
+            Some((key, value)) => {
+                values.insert(key.trim().to_string(), value.trim().to_string());
+            }
+            None => return Err(ParseError::UnknownLine(line).at_line(index + 1)),
+        }
+    }
+    Ok(FuzzerStats { values })
+}
+
+/// Row of `plot_data`. Columns missing in old AFL are None.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlotDataRow {
+    /// Seconds since start of fuzzing
+    pub time: u64,
+    pub corpus_count: u64,
+    /// Bitmap coverage in percent
+    pub map_size: f64,
+    pub saved_crashes: u64,
+    pub max_depth: u64,
+    pub total_execs: Option<u64>,
+    pub edges_found: Option<u64>,
+}
+
+impl PlotDataRow {
+    // Coverage compared to detect plateaus. Edges are finer than rounded percentage of bitmap.
+    fn coverage(&self) -> (u64, u64) {
+        (
+            self.edges_found.unwrap_or_default(),
+            (self.map_size * 100.0) as u64,
+        )
+    }
+}
+
+/// Contents of `plot_data`, i.e. time series of AFL's status
+#[derive(Debug, Clone, Default)]
+pub struct PlotData {
+    pub rows: Vec<PlotDataRow>,
+}
+
+impl PlotData {
+    /// Periods `(start, end)` in seconds lasting at least `min_duration` seconds where coverage did not grow
+    pub fn plateaus(&self, min_duration: u64) -> Vec<(u64, u64)> {
+        let mut plateaus = Vec::new();
+        let mut start: Option<&PlotDataRow> = None;
+        for row in self.rows.iter() {
+            match start {
+                Some(first) if first.coverage() == row.coverage() => continue,
+                Some(first) => {
+                    // NOTE: Plateau ends when the coverage grows
+                    if row.time.saturating_sub(first.time) >= min_duration {
+                        plateaus.push((first.time, row.time));
+                    }
+                    start = Some(row);
+                }
+                None => start = Some(row),
+            }
+        }
+        if let (Some(first), Some(last)) = (start, self.rows.last()) {
+            if last.time.saturating_sub(first.time) >= min_duration {
+                plateaus.push((first.time, last.time));
+            }
+        }
+        plateaus
+    }
+
+    /// Total executions at `time` seconds since start, taken from the last row until then
+    pub fn execs_at(&self, time: u64) -> Option<u64> {
+        let index = self.rows.partition_point(|row| row.time <= time);
+        self.rows.get(index.checked_sub(1)?)?.total_execs
+    }
+}
+
+/// Parses `plot_data` written by AFL++ (`relative_time`) or AFL (`unix_time`).
+/// Unix times are made relative to `start_time` (e.g. of `fuzzer_stats`) or to the first row.
+pub fn parse_plot_data_file<T: AsRef<Path>>(file: T, start_time: Option<u64>) -> Result<PlotData> {
+    let file = file.as_ref();
+    let reader = File::open(file).map_err(|why| ParseError::from(why).in_file(file))?;
+    parse_plot_data(reader, start_time).map_err(|why| why.in_file(file))
+}
+
+pub fn parse_plot_data<T: Read>(reader: T, start_time: Option<u64>) -> Result<PlotData> {
+    let mut columns: Vec<String> = Vec::new();
+    let mut rows = Vec::new();
+    let mut start_time = start_time;
+    for (index, line) in BufReader::new(reader).lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.map_err(|why| ParseError::from(why).at_line(line_number))?;
+        if let Some(header) = line.strip_prefix('#') {
+            columns = header.split(',').map(|v| v.trim().to_string()).collect();
+            continue;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let values: Vec<&str> = line.split(',').map(|v| v.trim()).collect();
+        let value = |names: &[&str]| {
+            names.iter().find_map(|name| {
+                columns
+                    .iter()
+                    .position(|column| column == name)
+                    .and_then(|index| values.get(index))
+            })
+        };
+        let number = |names: &[&str]| -> Result<Option<u64>> {
+            match value(names) {
+                Some(v) => v.parse().map(Some).map_err(|_| {
+                    ParseError::SyntaxError("Invalid number", v.to_string()).at_line(line_number)
+                }),
+                None => Ok(None),
+            }
+        };
+
+        let time = match (number(&["relative_time"])?, number(&["unix_time"])?) {
+            (Some(time), _) => time,
+            (None, Some(time)) => time.saturating_sub(*start_time.get_or_insert(time)),
+            (None, None) => {
+                return Err(ParseError::SyntaxError("Missing time", line).at_line(line_number))
+            }
+        };
+        let map_size = match value(&["map_size"]) {
+            Some(v) => v.trim_end_matches('%').parse().map_err(|_| {
+                ParseError::SyntaxError("Invalid map size", v.to_string()).at_line(line_number)
+            })?,
+            None => 0.0,
+        };
+        rows.push(PlotDataRow {
+            time,
+            corpus_count: number(&["corpus_count", "paths_total"])?.unwrap_or_default(),
+            map_size,
+            saved_crashes: number(&["saved_crashes", "unique_crashes"])?.unwrap_or_default(),
+            max_depth: number(&["max_depth"])?.unwrap_or_default(),
+            total_execs: number(&["total_execs"])?,
+            edges_found: number(&["edges_found"])?,
+        });
+    }
+    Ok(PlotData { rows })
+}
+
+/// Status files of an AFL instance (i.e. output directory of `-M`/`-S` or of a single fuzzer)
+#[derive(Debug, Clone)]
+pub struct AFLInstance {
+    pub directory: PathBuf,
+    pub stats: Option<FuzzerStats>,
+    pub plot_data: Option<PlotData>,
+}
+
+impl AFLInstance {
+    pub fn name(&self) -> String {
+        match self.stats.as_ref().and_then(|v| v.banner()) {
+            Some(banner) => banner.to_string(),
+            None => self.directory.display().to_string(),
+        }
+    }
+}
+
+/// Finds instances around `directories`, which may be instance directories, `queue/` in them or
+/// output directories of multi-instance runs. Directories without `fuzzer_stats` nor `plot_data` are ignored.
+pub fn find_afl_instances<T: AsRef<Path>>(directories: &[T]) -> Result<Vec<AFLInstance>> {
+    let is_instance = |directory: &Path| {
+        directory.join("fuzzer_stats").is_file() || directory.join("plot_data").is_file()
+    };
+
+    let mut found = Vec::new();
+    for directory in directories.iter() {
+        let directory = directory.as_ref();
+        if is_instance(directory) {
+            found.push(directory.to_path_buf());
+        } else if let Some(parent) = directory.parent().filter(|v| is_instance(v)) {
+            found.push(parent.to_path_buf());
+        } else if directory.is_dir() {
+            let entries = directory
+                .read_dir()
+                .map_err(|why| ParseError::from(why).in_file(directory))?;
+            for entry in entries {
+                let path = entry
+                    .map_err(|why| ParseError::from(why).in_file(directory))?
+                    .path();
+                if path.is_dir() && is_instance(&path) {
+                    found.push(path);
+                }
+            }
+        }
+    }
+    found.sort();
+    found.dedup();
+
+    let mut instances = Vec::new();
+    for directory in found {
+        let stats_file = directory.join("fuzzer_stats");
+        let stats = if stats_file.is_file() {
+            Some(parse_fuzzer_stats_file(&stats_file)?)
+        } else {
+            None
+        };
+        let plot_data_file = directory.join("plot_data");
+        let plot_data = if plot_data_file.is_file() {
+            let start_time = stats.as_ref().and_then(|v| v.get_u64("start_time"));
+            Some(parse_plot_data_file(&plot_data_file, start_time)?)
+        } else {
+            None
+        };
+        instances.push(AFLInstance {
+            directory,
+            stats,
+            plot_data,
+        });
+    }
+    Ok(instances)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::parser::afl_stats::{parse_fuzzer_stats, parse_plot_data};
+
+    #[test]
+    fn test_parse_fuzzer_stats() {
+        let text = "\
+start_time        : 1680000000
+last_update       : 1680003600
+fuzzer_pid        : 4242
+execs_done        : 1234567
+execs_per_sec     : 342.93
+paths_total       : 120
+bitmap_cvg        : 12.34%
+afl_banner        : main
+";
+        let stats = parse_fuzzer_stats(text.as_bytes()).unwrap();
+        assert_eq!(stats.banner(), Some("main"));
+        assert_eq!(stats.run_time(), Some(3600));
+        assert_eq!(stats.get_u64("execs_done"), Some(1234567));
+        // NOTE: Old name of `corpus_count`
+        assert_eq!(stats.get_u64("corpus_count"), Some(120));
+        assert_eq!(stats.get_f64("bitmap_cvg"), Some(12.34));
+        assert!(parse_fuzzer_stats("no separator\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_parse_plot_data() {
+        let text = "\
+# relative_time, cycles_done, cur_item, corpus_count, pending_total, pending_favs, map_size, saved_crashes, saved_hangs, max_depth, execs_per_sec, total_execs, edges_found
+0, 0, 0, 1, 1, 1, 1.20%, 0, 0, 1, 100.00, 0, 10
+60, 0, 0, 5, 4, 1, 2.40%, 0, 0, 2, 100.00, 6000, 20
+700, 1, 3, 5, 2, 0, 2.40%, 0, 0, 2, 100.00, 70000, 20
+800, 1, 3, 6, 2, 0, 2.50%, 1, 0, 3, 100.00, 80000, 21
+900, 1, 3, 6, 2, 0, 2.50%, 1, 0, 3, 100.00, 90000, 21
+";
+        let plot_data = parse_plot_data(text.as_bytes(), None).unwrap();
+        assert_eq!(plot_data.rows.len(), 5);
+        assert_eq!(plot_data.rows[1].map_size, 2.4);
+        assert_eq!(plot_data.plateaus(600), vec![(60, 800)]);
+        assert_eq!(plot_data.plateaus(100), vec![(60, 800), (800, 900)]);
+        assert_eq!(plot_data.execs_at(65), Some(6000));
+        assert_eq!(plot_data.execs_at(800), Some(80000));
+
+        // Old AFL writes unix time and old column names
+        let text = "\
+# unix_time, cycles_done, cur_path, paths_total, pending_total, pending_favs, map_size, unique_crashes, unique_hangs, max_depth, execs_per_sec
+1680000010, 0, 0, 1, 1, 1, 1.20%, 0, 0, 1, 100.00
+1680000070, 0, 0, 5, 4, 1, 2.40%, 2, 0, 2, 100.00
+";
+        let plot_data = parse_plot_data(text.as_bytes(), Some(1680000000)).unwrap();
+        assert_eq!(plot_data.rows[1].time, 70);
+        assert_eq!(plot_data.rows[1].corpus_count, 5);
+        assert_eq!(plot_data.rows[1].saved_crashes, 2);
+        assert_eq!(plot_data.execs_at(70), None);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+use crate::seed_tree::node_name::NodeName;
+use std::fmt;
+
+pub type LineNumber = usize;
+pub type ColumnNumber = usize;
+
+/// Problem found in input by lenient parser. Line and column are 1-origin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub line: LineNumber,
+    pub column: ColumnNumber,
+    pub kind: DiagnosticKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    // Line not matching any syntax. The line is skipped.
+    UnknownLine(String),
+    // Last line without newline not matching any syntax (e.g. fuzzer is killed while writing). The line is skipped.
+    Truncated(String),
+    // Node is declared again. Holds line of first declaration.
+    DuplicateNode(NodeName, LineNumber),
+    // Same edge is declared again. The edge is skipped.
+    DuplicateEdge(MutationGraphEdge),
+    // Edge from a node to itself. Stored as weak edge.
+    SelfLoop(MutationGraphEdge),
+    // Node is referred by edges but never declared
+    UndeclaredNode(NodeName),
+    // Edge making closed chain or giving second parent to a node. Stored as weak edge.
+    WeakEdge(MutationGraphEdge),
+}
+
+impl DiagnosticKind {
+    pub const NAMES: &'static [&'static str] = &[
+        "unknown-line",
+        "truncated",
+        "duplicate-node",
+        "duplicate-edge",
+        "self-loop",
+        "undeclared-node",
+        "weak-edge",
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UnknownLine(_) => "unknown-line",
+            Self::Truncated(_) => "truncated",
+            Self::DuplicateNode(_, _) => "duplicate-node",
+            Self::DuplicateEdge(_) => "duplicate-edge",
+            Self::SelfLoop(_) => "self-loop",
+            Self::UndeclaredNode(_) => "undeclared-node",
+            Self::WeakEdge(_) => "weak-edge",
+        }
+    }
+
+    /// Whether content of input is lost (i.e. lines are skipped)
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::UnknownLine(_) | Self::Truncated(_))
+    }
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownLine(line) => write!(f, "unknown line: {:?}", line.trim_end()),
+            Self::Truncated(line) => write!(f, "truncated line: {:?}", line),
+            Self::DuplicateNode(name, first) => write!(
+                f,
+                "node {:?} is declared again (first declared at line {})",
+                name, first
+            ),
+            Self::DuplicateEdge(edge) => write!(
+                f,
+                "edge {:?} -> {:?} is declared again",
+                edge.parent, edge.child
+            ),
+            Self::SelfLoop(edge) => write!(
+                f,
+                "edge from node {:?} to itself [label={:?}]",
+                edge.parent, edge.label
+            ),
+            Self::UndeclaredNode(name) => write!(f, "node {:?} is not declared", name),
+            Self::WeakEdge(edge) => write!(
+                f,
+                "edge {:?} -> {:?} makes closed chain or second parent, demoted to weak edge",
+                edge.parent, edge.child
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = if self.kind.is_error() {
+            "error"
+        } else {
+            "warning"
+        };
+        write!(
+            f,
+            "{}:{}: {}[{}]: {}",
+            self.line,
+            self.column,
+            severity,
+            self.kind.name(),
+            self.kind
+        )
+    }
+}
@@ -1,9 +1,9 @@
-use crate::seed_tree::error::MutationGraphError;
-
+use std::fmt;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 type ErrorMessage = &'static str;
+type LineNumber = usize;
 
 #[derive(Debug)]
 pub enum ParseError {
@@ -14,7 +14,70 @@ pub enum ParseError {
     UnexpectedFilePath(PathBuf),
     UnexpectedDirectoryPath(PathBuf),
     StringEncoding,
-    MutationGraph(MutationGraphError),
+    // Error at line of input (1-origin)
+    AtLine(LineNumber, Box<ParseError>),
+    // Error on parsing file or directory
+    InFile(PathBuf, Box<ParseError>),
+}
+
+impl ParseError {
+    pub fn at_line(self, line: LineNumber) -> Self {
+        Self::AtLine(line, Box::new(self))
+    }
+
+    pub fn in_file<P: AsRef<Path>>(self, path: P) -> Self {
+        Self::InFile(path.as_ref().to_path_buf(), Box::new(self))
+    }
+
+    /// Line number where this error occurred if known
+    pub fn line(&self) -> Option<LineNumber> {
+        match self {
+            Self::AtLine(line, _) => Some(*line),
+            Self::InFile(_, error) => error.line(),
+            _ => None,
+        }
+    }
+
+    /// File where this error occurred if known
+    pub fn file(&self) -> Option<&Path> {
+        match self {
+            Self::InFile(path, _) => Some(path),
+            Self::UnexpectedFilePath(path) | Self::UnexpectedDirectoryPath(path) => Some(path),
+            Self::AtLine(_, error) => error.file(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IoError(error) => write!(f, "{}", error),
+            Self::RegexError(error) => write!(f, "{}", error),
+            Self::UnknownLine(line) => write!(f, "unknown line: {:?}", line.trim_end()),
+            Self::SyntaxError(message, text) => write!(f, "{}: {:?}", message, text),
+            Self::UnexpectedFilePath(path) => {
+                write!(f, "expected a directory but got a file: {}", path.display())
+            }
+            Self::UnexpectedDirectoryPath(path) => {
+                write!(f, "expected a file but got a directory: {}", path.display())
+            }
+            Self::StringEncoding => write!(f, "file name is not valid UTF-8"),
+            Self::AtLine(line, error) => write!(f, "line {}: {}", line, error),
+            Self::InFile(path, error) => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::IoError(error) => Some(error),
+            Self::RegexError(error) => Some(error),
+            Self::AtLine(_, error) | Self::InFile(_, error) => Some(error.as_ref()),
+            _ => None,
+        }
+    }
 }
 
 impl From<io::Error> for ParseError {
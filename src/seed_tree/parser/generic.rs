@@ -6,67 +6,141 @@ use crate::seed_tree::MutationGraph;
 use regex::Regex;
 use std::io::{BufRead, BufReader, Read};
 
-// NOTE: As of 2021/10/26 something went wrong
+/// Parses seed tree in DOT format, including output of `parse` subcommand. Errors are annotated with line number.
 pub fn parse_generic_seed_tree_file<T: Read>(file: T) -> Result<MutationGraph> {
     let mut graph = MutationGraph::new();
 
     {
         // Seed tree syntax in dot format
-        let node = Regex::new("^\\s*\"([\\d[:alpha:]]+)\"\\s*$").map_err(ParseError::RegexError)?;
+        // NOTE: Node attributes are ignored
+        let node = Regex::new("^\\s*\"([^\"]+)\"\\s*(\\[[^\\]]*\\])?\\s*;?\\s*$")
+            .map_err(ParseError::RegexError)?;
         // NOTE: Ignore attributes except `label`
         let edge = Regex::new(
-            "^\\s*\"(\\w+)\"\\s*\\->\\s*\"(\\w+)\"\\s*\\[label\\s*=\\s*\"(.*)\"(,[^\\]]*)?\\]\\s*;\\s*$",
+            "^\\s*\"([^\"]+)\"\\s*\\->\\s*\"([^\"]+)\"\\s*\\[label\\s*=\\s*\"([^\"]*)\"(,[^\\]]*)?\\]\\s*;\\s*$",
         )
             .map_err(ParseError::RegexError)?;
 
         // Parse lines of given file along with above syntax
         let mut reader = BufReader::new(file);
         let mut line = String::new();
+        let mut line_number = 0;
         loop {
             line.clear();
-            if reader.read_line(&mut line).map_err(ParseError::IoError)? == 0 {
+            line_number += 1;
+            let length = reader
+                .read_line(&mut line)
+                .map_err(|why| ParseError::from(why).at_line(line_number))?;
+            if length == 0 {
                 // reached EOF
                 break;
             }
 
+            // Skip graph declaration and notes
+            let trimmed = line.trim();
+            if trimmed.is_empty()
+                || trimmed.starts_with("digraph")
+                || trimmed == "}"
+                || trimmed.starts_with("{rank=same;")
+            {
+                continue;
+            }
+
             if let Some(m) = node.captures(&line) {
-                if m.len() == 2 {
+                if m.len() == 3 {
                     match m.get(1) {
                         Some(v) => graph.add_node(&MutationGraphNode::new(&v.as_str().to_string())),
                         None => {
                             return Err(ParseError::SyntaxError(
                                 "Missing node value",
                                 m[0].to_string(),
-                            ))
+                            )
+                            .at_line(line_number))
                         }
                     }
                     continue;
                 }
             }
             if let Some(m) = edge.captures(&line) {
-                if m.len() == 4 {
+                if m.len() == 5 {
                     match (m.get(1), m.get(2), m.get(3)) {
                         (Some(parent), Some(child), Some(label)) => {
-                            graph.add_edge(&MutationGraphEdge {
+                            let edge = MutationGraphEdge {
                                 parent: parent.as_str().to_string(),
                                 child: child.as_str().to_string(),
                                 label: label.as_str().to_string(),
-                            })
+                            };
+                            // NOTE: Weak edges are drawn with dashed line
+                            match m.get(4) {
+                                Some(attributes)
+                                    if attributes.as_str().contains("style=dashed") =>
+                                {
+                                    graph.add_weak_edge(&edge)
+                                }
+                                _ => graph.add_edge(&edge),
+                            }
                         }
                         _ => {
                             return Err(ParseError::SyntaxError(
                                 "Unexpected edge node",
                                 m[0].to_string(),
-                            ))
+                            )
+                            .at_line(line_number))
                         }
                     }
 
                     continue;
                 }
             }
-            return Err(ParseError::UnknownLine(line.clone()));
+            return Err(ParseError::UnknownLine(line.clone()).at_line(line_number));
         }
     }
 
     Ok(graph)
 }
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+    use crate::seed_tree::node_name::NodeName;
+    use crate::seed_tree::parser::error::ParseError;
+    use crate::seed_tree::parser::generic::parse_generic_seed_tree_file;
+    use crate::seed_tree::plot_options::PlotOptions;
+    use crate::seed_tree::MutationGraph;
+
+    #[test]
+    fn test_parse_dot_graph() {
+        let mut graph = MutationGraph::new();
+        for (parent, child) in [("000000", "000001"), ("000001", "crash-000000")].iter() {
+            graph.add_edge(&MutationGraphEdge {
+                parent: NodeName::from(*parent),
+                child: NodeName::from(*child),
+                label: String::from("havoc"),
+            });
+        }
+        graph.add_edge(&MutationGraphEdge {
+            parent: NodeName::from("crash-000000"),
+            child: NodeName::from("000000"),
+            label: String::from("splice"),
+        });
+        let dot = graph.dot_graph(PlotOptions::none()).unwrap();
+
+        let parsed = parse_generic_seed_tree_file(dot.as_bytes()).unwrap();
+        assert_eq!(parsed.dot_graph(PlotOptions::none()).unwrap(), dot);
+    }
+
+    #[test]
+    fn test_parse_error_line_number() {
+        let dot = "digraph {\n\"a\" []\n\"a\" => \"b\"\n}\n";
+        match parse_generic_seed_tree_file(dot.as_bytes()) {
+            Err(why @ ParseError::AtLine(3, _)) => {
+                assert_eq!(why.line(), Some(3));
+                assert_eq!(
+                    why.in_file("graph.dot").to_string(),
+                    "graph.dot: line 3: unknown line: \"\\\"a\\\" => \\\"b\\\"\""
+                );
+            }
+            other => panic!("Unexpected result: {:?}", other.map(|_| ())),
+        }
+    }
+}
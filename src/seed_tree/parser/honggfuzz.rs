@@ -0,0 +1,197 @@
+use super::error::ParseError;
+use super::result::Result;
+use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+use crate::seed_tree::mutation_graph_node::MutationGraphNode;
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::MutationGraph;
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Name of report file which honggfuzz writes in its workspace (`-W`)
+pub const HONGGFUZZ_REPORT_FILE: &str = "HONGGFUZZ.REPORT.TXT";
+
+/// Crash recorded in honggfuzz's report
+///
+/// ```text
+/// ORIG_FNAME: 4e0b1c5f3c6e1f0a.00000010.honggfuzz.cov
+/// FUZZ_FNAME: ./SIGSEGV.PC.555555555196.STACK.badbeef.CODE.1.ADDR.0.INSTR.mov____%eax,(%rdx).fuzz
+/// SIGNAL: SIGSEGV (11)
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HonggfuzzCrash {
+    /// File name of corpus input which the crash is mutated from. None for dynamically generated inputs.
+    pub orig_file_name: Option<NodeName>,
+    /// Path of the crash input as written in report
+    pub fuzz_file: PathBuf,
+    pub signal: String,
+    pub line: usize,
+}
+
+pub fn parse_honggfuzz_report_file<T: AsRef<Path>>(file: T) -> Result<Vec<HonggfuzzCrash>> {
+    let file = file.as_ref();
+    let reader = File::open(file).map_err(|why| ParseError::from(why).in_file(file))?;
+    parse_honggfuzz_report(reader).map_err(|why| why.in_file(file))
+}
+
+/// Extracts crashes from honggfuzz's report. Each crash is a section of `key: value` lines separated by `=====` lines.
+pub fn parse_honggfuzz_report<T: Read>(reader: T) -> Result<Vec<HonggfuzzCrash>> {
+    let mut crashes = Vec::new();
+    let mut section = Section::default();
+    for (index, line) in BufReader::new(reader).lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.map_err(|why| ParseError::from(why).at_line(line_number))?;
+        if line.starts_with("=====") {
+            crashes.extend(std::mem::take(&mut section).crash()?);
+            continue;
+        }
+        let (key, value) = match line.split_once(':') {
+            Some((key, value)) => (key.trim(), value.trim()),
+            None => continue,
+        };
+        match key {
+            "ORIG_FNAME" => section.orig_file_name = Some(value.to_string()),
+            "FUZZ_FNAME" => section.fuzz_file = Some((PathBuf::from(value), line_number)),
+            "SIGNAL" => section.signal = value.split_whitespace().next().map(|v| v.to_string()),
+            _ => (),
+        }
+    }
+    crashes.extend(section.crash()?);
+    Ok(crashes)
+}
+
+// Fields of a section of report. Sections other than crashes (e.g. header) do not have FUZZ_FNAME.
+#[derive(Debug, Default)]
+struct Section {
+    orig_file_name: Option<String>,
+    fuzz_file: Option<(PathBuf, usize)>,
+    signal: Option<String>,
+}
+
+impl Section {
+    fn crash(self) -> Result<Option<HonggfuzzCrash>> {
+        let (fuzz_file, line) = match self.fuzz_file {
+            Some(fuzz_file) => fuzz_file,
+            None => return Ok(None),
+        };
+        let orig_file_name = match self.orig_file_name {
+            // NOTE: Inputs not from corpus files are named like `[DYNAMIC]`
+            Some(name) if name.starts_with('[') || name.is_empty() => None,
+            Some(name) => Some(file_name_of(&name)),
+            None => {
+                return Err(ParseError::SyntaxError(
+                    "FUZZ_FNAME without ORIG_FNAME",
+                    fuzz_file.display().to_string(),
+                )
+                .at_line(line))
+            }
+        };
+        Ok(Some(HonggfuzzCrash {
+            orig_file_name,
+            fuzz_file,
+            signal: self.signal.unwrap_or_default(),
+            line,
+        }))
+    }
+}
+
+fn file_name_of(path: &str) -> NodeName {
+    Path::new(path)
+        .file_name()
+        .map(|v| v.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string())
+}
+
+/// Builds seed tree from honggfuzz's workspace directories or report files.
+/// Crash inputs become crashed nodes attached to corpus inputs which they are mutated from, with edges labeled by signals.
+/// Corpus inputs are named by their file names and have no file path (see `--seeds` or `--corpus`).
+pub fn parse_honggfuzz_workspaces<P: AsRef<Path>>(inputs: &[P]) -> Result<MutationGraph> {
+    let mut graph = MutationGraph::new();
+    for input in inputs.iter() {
+        let input = input.as_ref();
+        let (report_file, workspace) = if input.is_dir() {
+            (input.join(HONGGFUZZ_REPORT_FILE), input)
+        } else {
+            (
+                input.to_path_buf(),
+                input.parent().unwrap_or_else(|| Path::new(".")),
+            )
+        };
+        for crash in parse_honggfuzz_report_file(&report_file)?.iter() {
+            let name = file_name_of(&crash.fuzz_file.to_string_lossy());
+            // NOTE: Report has paths at fuzzing time, which may be moved with workspace
+            let file = if crash.fuzz_file.is_file() {
+                crash.fuzz_file.clone()
+            } else {
+                workspace.join(&name)
+            };
+            let mut node = MutationGraphNode::new(&name);
+            node.crashed = true;
+            if file.is_file() {
+                node.file = file;
+            }
+            graph.add_node(&node);
+            if let Some(ref orig_file_name) = crash.orig_file_name {
+                graph.add_edge(&MutationGraphEdge {
+                    parent: orig_file_name.clone(),
+                    child: name,
+                    label: crash.signal.clone(),
+                });
+            }
+        }
+    }
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::node_name::NodeName;
+    use crate::seed_tree::parser::honggfuzz::{parse_honggfuzz_report, HonggfuzzCrash};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_parse_honggfuzz_report() {
+        let report = "\
+=====================================================================
+TIME: 2023-04-01.12:00:00
+=====================================================================
+FUZZER ARGS:
+ mutationsPerRun : 5
+ fuzzTarget      : ./target
+CRASH:
+DESCRIPTION:
+ORIG_FNAME: 4e0b1c5f3c6e1f0a.00000010.honggfuzz.cov
+FUZZ_FNAME: /work/SIGSEGV.PC.555555555196.STACK.badbeef.CODE.1.ADDR.0.INSTR.mov.fuzz
+PID: 4242
+SIGNAL: SIGSEGV (11)
+PC: 0x555555555196
+STACK HASH: 0000000000badbeef
+STACK:
+ <0x0000555555555196> [LLVMFuzzerTestOneInput():12 at target.c]
+=====================================================================
+TIME: 2023-04-01.12:05:00
+=====================================================================
+CRASH:
+ORIG_FNAME: [DYNAMIC]
+FUZZ_FNAME: /work/SIGABRT.PC.7ffff7a42e87.STACK.cafe.CODE.-6.ADDR.0.INSTR.mov.fuzz
+SIGNAL: SIGABRT (6)
+=====================================================================
+";
+        let crashes = parse_honggfuzz_report(report.as_bytes()).unwrap();
+        assert_eq!(
+            crashes[0],
+            HonggfuzzCrash {
+                orig_file_name: Some(NodeName::from("4e0b1c5f3c6e1f0a.00000010.honggfuzz.cov")),
+                fuzz_file: PathBuf::from(
+                    "/work/SIGSEGV.PC.555555555196.STACK.badbeef.CODE.1.ADDR.0.INSTR.mov.fuzz"
+                ),
+                signal: String::from("SIGSEGV"),
+                line: 10,
+            }
+        );
+        assert_eq!(crashes[1].orig_file_name, None);
+        assert_eq!(crashes[1].signal, "SIGABRT");
+        assert_eq!(crashes.len(), 2);
+    }
+}
@@ -0,0 +1,282 @@
+use super::error::ParseError;
+use super::result::Result;
+use crate::seed_tree::hash_cache::FileHashCache;
+use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+use crate::seed_tree::mutation_graph_node::MutationGraphNode;
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::MutationGraph;
+
+use regex::Regex;
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Names of directories of LibAFL's objective corpus (i.e. crashes), whose inputs are crashed nodes
+pub const LIBAFL_SOLUTIONS_DIRS: &[&str] = &["crashes", "solutions", "objectives"];
+
+/// Metadata of a testcase taken from `.<name>.metadata` written by LibAFL's on-disk corpora in JSON format
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LibAFLMetadata {
+    /// Corpus id of the testcase if recorded
+    pub id: Option<usize>,
+    /// Corpus id of the testcase which this testcase is mutated from. None for initial inputs.
+    pub parent_id: Option<usize>,
+    pub executions: Option<u64>,
+}
+
+/// Extracts fields of lineage from JSON of metadata file
+pub fn parse_libafl_metadata(json: &str) -> Result<LibAFLMetadata> {
+    MetadataParser::new()?.parse(json)
+}
+
+// NOTE: Metadata is read with a pattern instead of JSON parser. Fields are taken from their first occurrences,
+//       since LibAFL writes testcase fields (`parent_id`, `executions`) out of nested metadata maps.
+struct MetadataParser {
+    field: Regex,
+}
+
+impl MetadataParser {
+    fn new() -> Result<Self> {
+        Ok(Self {
+            field: Regex::new("\"(id|corpus_id|parent_id|executions)\"\\s*:\\s*(null|\\d+)")?,
+        })
+    }
+
+    fn parse(&self, json: &str) -> Result<LibAFLMetadata> {
+        let mut fields: HashMap<&str, Option<u64>> = HashMap::new();
+        for m in self.field.captures_iter(json) {
+            let (key, value) = match (m.get(1), m.get(2)) {
+                (Some(key), Some(value)) => (key.as_str(), value.as_str()),
+                _ => continue,
+            };
+            let value =
+                match value {
+                    "null" => None,
+                    value => Some(value.parse::<u64>().map_err(|_| {
+                        ParseError::SyntaxError("Too large number", value.to_string())
+                    })?),
+                };
+            fields.entry(key).or_insert(value);
+        }
+        let field = |key: &str| fields.get(key).cloned().flatten();
+        Ok(LibAFLMetadata {
+            id: field("id")
+                .or_else(|| field("corpus_id"))
+                .map(|v| v as usize),
+            parent_id: field("parent_id").map(|v| v as usize),
+            executions: field("executions"),
+        })
+    }
+}
+
+// Testcase on disk with its metadata
+#[derive(Debug)]
+struct Testcase {
+    file: PathBuf,
+    name: NodeName,
+    crashed: bool,
+    metadata: LibAFLMetadata,
+}
+
+/// Builds seed tree from LibAFL's on-disk corpus `directories` (e.g. `corpus/` and `crashes/`).
+///
+/// Nodes are named by file names of testcases, and edges are built from `parent_id` of metadata files.
+/// Ids of testcases are taken from metadata if recorded, otherwise assigned in order of modification time of files
+/// in each directory as LibAFL assigns ids in order of addition.
+/// Parents of testcases in objective corpus (see `LIBAFL_SOLUTIONS_DIRS`) are looked up from other directories.
+pub fn parse_libafl_corpus_directories_with_cache<P: AsRef<Path>>(
+    directories: &[P],
+    cache: &mut FileHashCache,
+    jobs: usize,
+) -> Result<MutationGraph> {
+    let parser = MetadataParser::new()?;
+    let mut corpus_ids: HashMap<usize, NodeName> = HashMap::new();
+    let mut testcases = Vec::new();
+    for directory in directories.iter() {
+        let directory = directory.as_ref();
+        let crashed = directory
+            .file_name()
+            .and_then(OsStr::to_str)
+            .is_some_and(|name| LIBAFL_SOLUTIONS_DIRS.contains(&name));
+        let mut entries = read_testcases(directory, crashed, &parser)?;
+
+        let mut next_id = 0;
+        for testcase in entries.iter_mut() {
+            let id = *testcase.metadata.id.get_or_insert(next_id);
+            next_id = id + 1;
+            if crashed {
+                continue;
+            }
+            if let Some(other) = corpus_ids.insert(id, testcase.name.clone()) {
+                log::warn!(
+                    "Corpus id {} is shared by {:?} and {:?}",
+                    id,
+                    other,
+                    testcase.name
+                );
+            }
+        }
+        testcases.append(&mut entries);
+    }
+
+    let files: Vec<&PathBuf> = testcases.iter().map(|v| &v.file).collect();
+    let hashes = cache.calc_file_hashes(&files, jobs);
+
+    let mut graph = MutationGraph::new();
+    for (testcase, hash) in testcases.iter().zip(hashes) {
+        let hash = hash.map_err(|why| ParseError::from(why).in_file(&testcase.file))?;
+        graph.add_node(&MutationGraphNode::new_with_metadata(
+            &testcase.name,
+            testcase.crashed,
+            &testcase.file,
+            &hash,
+        ));
+        let parent_id = match testcase.metadata.parent_id {
+            Some(parent_id) => parent_id,
+            None => continue,
+        };
+        match corpus_ids.get(&parent_id) {
+            // NOTE: LibAFL does not record mutators on disk
+            Some(parent) => graph.add_edge(&MutationGraphEdge {
+                parent: parent.clone(),
+                child: testcase.name.clone(),
+                label: String::new(),
+            }),
+            None => log::warn!(
+                "Parent id {} of {:?} is not found in corpus",
+                parent_id,
+                testcase.file
+            ),
+        }
+    }
+    Ok(graph)
+}
+
+// Lists testcases in `directory` in order of addition to corpus
+fn read_testcases(
+    directory: &Path,
+    crashed: bool,
+    parser: &MetadataParser,
+) -> Result<Vec<Testcase>> {
+    if !directory.is_dir() {
+        return Err(ParseError::UnexpectedFilePath(directory.to_path_buf()));
+    }
+    let mut testcases = Vec::new();
+    let entries = directory
+        .read_dir()
+        .map_err(|why| ParseError::from(why).in_file(directory))?;
+    for entry in entries {
+        let file = entry
+            .map_err(|why| ParseError::from(why).in_file(directory))?
+            .path();
+        let name = match file.file_name().and_then(OsStr::to_str) {
+            Some(name) => name.to_string(),
+            None => return Err(ParseError::StringEncoding.in_file(&file)),
+        };
+        // NOTE: Metadata and lock files of LibAFL are hidden
+        if name.starts_with('.') || !file.is_file() {
+            continue;
+        }
+
+        let metadata_file = directory.join(format!(".{}.metadata", name));
+        let metadata = if metadata_file.is_file() {
+            let json = fs::read_to_string(&metadata_file)
+                .map_err(|why| ParseError::from(why).in_file(&metadata_file))?;
+            parser
+                .parse(&json)
+                .map_err(|why| why.in_file(&metadata_file))?
+        } else {
+            LibAFLMetadata::default()
+        };
+        let modified = fs::metadata(&file)
+            .and_then(|v| v.modified())
+            .map_err(|why| ParseError::from(why).in_file(&file))?;
+        testcases.push((
+            modified,
+            Testcase {
+                file,
+                name,
+                crashed,
+                metadata,
+            },
+        ));
+    }
+    testcases.sort_by(|(a_modified, a), (b_modified, b)| {
+        (a.metadata.id, a_modified, &a.name).cmp(&(b.metadata.id, b_modified, &b.name))
+    });
+    Ok(testcases.into_iter().map(|(_, v)| v).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::hash_cache::FileHashCache;
+    use crate::seed_tree::node_name::NodeName;
+    use crate::seed_tree::parser::libafl::{
+        parse_libafl_corpus_directories_with_cache, parse_libafl_metadata, LibAFLMetadata,
+    };
+    use std::fs;
+
+    #[test]
+    fn test_parse_libafl_metadata() {
+        let json = r#"{"metadata":{"map":{}},"exec_time":{"secs":0,"nanos":1200},"executions":42,"parent_id":3}"#;
+        assert_eq!(
+            parse_libafl_metadata(json).unwrap(),
+            LibAFLMetadata {
+                id: None,
+                parent_id: Some(3),
+                executions: Some(42),
+            }
+        );
+        assert_eq!(
+            parse_libafl_metadata(r#"{"id": 7, "parent_id": null}"#).unwrap(),
+            LibAFLMetadata {
+                id: Some(7),
+                parent_id: None,
+                executions: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_libafl_corpus() {
+        let root = std::env::temp_dir().join(format!(
+            "seed-tree-analyzer-test-libafl-{}",
+            std::process::id()
+        ));
+        let corpus = root.join("corpus");
+        let crashes = root.join("crashes");
+        fs::create_dir_all(&corpus).unwrap();
+        fs::create_dir_all(&crashes).unwrap();
+        for (directory, name, contents, metadata) in [
+            (&corpus, "seed", "a", r#"{"id":0,"parent_id":null}"#),
+            (&corpus, "b1", "ab", r#"{"id":1,"parent_id":0}"#),
+            (&corpus, "c2", "abc", r#"{"id":2,"parent_id":1}"#),
+            (&crashes, "d0", "abcd", r#"{"id":0,"parent_id":2}"#),
+        ]
+        .iter()
+        {
+            fs::write(directory.join(name), contents).unwrap();
+            fs::write(directory.join(format!(".{}.metadata", name)), metadata).unwrap();
+        }
+        fs::write(corpus.join(".b1.lafl_lock"), "").unwrap();
+
+        let graph = parse_libafl_corpus_directories_with_cache(
+            &[&corpus, &crashes],
+            &mut FileHashCache::new(),
+            1,
+        );
+        let _ = fs::remove_dir_all(&root);
+        let graph = graph.unwrap();
+
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(
+            graph.predecessors_of(&NodeName::from("d0")).unwrap(),
+            vec!["seed", "b1", "c2"]
+        );
+        let crash = graph.get_node(&NodeName::from("d0")).unwrap();
+        assert!(crash.crashed);
+        assert_eq!(crash.file, crashes.join("d0"));
+        assert!(!graph.get_node(&NodeName::from("c2")).unwrap().crashed);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::seed_tree::corpus::ARTIFACT_PREFIXES;
+use crate::seed_tree::file_hash::FileHash;
+use crate::seed_tree::hash_algorithm::HashAlgorithm;
+use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+use crate::seed_tree::mutation_graph_node::MutationGraphNode;
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::parser::diagnostic::LineNumber;
+use crate::seed_tree::parser::error::ParseError;
+use crate::seed_tree::parser::result::Result;
+use crate::seed_tree::MutationGraph;
+use regex::Regex;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArtifactKind {
+    Crash,
+    Leak,
+    Timeout,
+    OutOfMemory,
+    SlowUnit,
+}
+
+impl ArtifactKind {
+    // Kind of artifact named `name` (e.g. `crash-<SHA-1>`) with rest of name
+    fn of(name: &str) -> Option<(Self, &str)> {
+        let kinds = [
+            Self::Crash,
+            Self::Leak,
+            Self::Timeout,
+            Self::OutOfMemory,
+            Self::SlowUnit,
+        ];
+        ARTIFACT_PREFIXES
+            .iter()
+            .zip(kinds.iter())
+            .find_map(|(prefix, kind)| name.strip_prefix(prefix).map(|rest| (*kind, rest)))
+    }
+}
+
+/// Input written by libFuzzer on finding a problem, reported in its log as
+///
+/// ```text
+/// MS: 1 ChangeBinInt-; base unit: c298122410da09836c59484e995c287294c31394
+/// artifact_prefix='./'; Test unit written to ./crash-235641cefe524570bf0df6a3b3722535ce2dbbf7
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Artifact {
+    pub kind: ArtifactKind,
+    /// File name of the artifact (e.g. `crash-<SHA-1>`)
+    pub name: NodeName,
+    /// Path of the artifact as written in log
+    pub path: PathBuf,
+    /// SHA-1 of the artifact taken from its name
+    pub hash: FileHash,
+    /// Sequence of mutators (e.g. `ChangeBinInt-`). Empty if not reported.
+    pub mutations: String,
+    /// Unit which the artifact is mutated from. None for initial inputs.
+    pub base_unit: Option<NodeName>,
+    /// Line of log where the artifact is reported
+    pub line: LineNumber,
+}
+
+impl Artifact {
+    /// Inserts this artifact into `graph` as crashed node attached to its base unit
+    pub fn add_to(&self, graph: &mut MutationGraph) {
+        graph.add_node(&MutationGraphNode::new_with_metadata(
+            &self.name, true, &self.path, &self.hash,
+        ));
+        if let Some(ref base_unit) = self.base_unit {
+            graph.add_edge(&MutationGraphEdge {
+                parent: base_unit.clone(),
+                child: self.name.clone(),
+                label: self.mutations.clone(),
+            });
+        }
+    }
+}
+
+pub fn parse_libfuzzer_log_file<T: AsRef<Path>>(file: T) -> Result<Vec<Artifact>> {
+    let file = file.as_ref();
+    let reader = File::open(file).map_err(|why| ParseError::from(why).in_file(file))?;
+    parse_libfuzzer_log(reader).map_err(|why| why.in_file(file))
+}
+
+/// Extracts artifacts from libFuzzer's log (i.e. stderr). Lines other than reports of artifacts are ignored.
+pub fn parse_libfuzzer_log<T: Read>(reader: T) -> Result<Vec<Artifact>> {
+    // NOTE: Base unit is omitted by old libFuzzer, and is zeros for initial inputs
+    let mutation_sequence = Regex::new("^MS: \\d+ ([^;]*);(?: base unit: ([0-9a-f]{40}))?")
+        .map_err(ParseError::RegexError)?;
+    let test_unit = Regex::new("Test unit written to (\\S+)").map_err(ParseError::RegexError)?;
+
+    let mut artifacts = Vec::new();
+    // Mutation sequence reported last, which is consumed by next artifact
+    let mut last: Option<(String, Option<NodeName>)> = None;
+    let mut reader = BufReader::new(reader);
+    let mut line = String::new();
+    let mut line_number = 0;
+    loop {
+        line.clear();
+        line_number += 1;
+        // NOTE: Logs may contain outputs of target program which are not valid UTF-8
+        let mut bytes = Vec::new();
+        let length = reader
+            .read_until(b'\n', &mut bytes)
+            .map_err(|why| ParseError::from(why).at_line(line_number))?;
+        if length == 0 {
+            // reached EOF
+            break;
+        }
+        line.push_str(&String::from_utf8_lossy(&bytes));
+
+        if let Some(m) = mutation_sequence.captures(&line) {
+            let mutations = m.get(1).map_or("", |v| v.as_str()).trim().to_string();
+            let base_unit = m
+                .get(2)
+                .map(|v| v.as_str())
+                .filter(|v| v.chars().any(|c| c != '0'))
+                .map(|v| v.to_string());
+            last = Some((mutations, base_unit));
+            continue;
+        }
+        if let Some(m) = test_unit.captures(&line) {
+            let path = PathBuf::from(&m[1]);
+            let name = match path.file_name() {
+                Some(name) => name.to_string_lossy().to_string(),
+                None => continue,
+            };
+            let (kind, hash) = match ArtifactKind::of(&name) {
+                Some((kind, hash)) => (
+                    kind,
+                    FileHash::from_hex(HashAlgorithm::Sha1, hash).unwrap_or_default(),
+                ),
+                None => {
+                    log::warn!("Unknown artifact at line {}: {:?}", line_number, name);
+                    continue;
+                }
+            };
+            let (mutations, base_unit) = last.take().unwrap_or_default();
+            artifacts.push(Artifact {
+                kind,
+                name,
+                path,
+                hash,
+                mutations,
+                base_unit,
+                line: line_number,
+            });
+        }
+    }
+    Ok(artifacts)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::parser::libfuzzer::artifact::{parse_libfuzzer_log, ArtifactKind};
+    use crate::seed_tree::parser::libfuzzer::parse_libfuzzer_mutation_graph_file;
+    use std::path::PathBuf;
+
+    const LOG: &str = "\
+INFO: Seed: 3918206239
+#2\tINITED cov: 3 ft: 4 corp: 1/1b exec/s: 0 rss: 28Mb
+==10928==ERROR: AddressSanitizer: heap-buffer-overflow on address 0x629000009748
+SUMMARY: AddressSanitizer: heap-buffer-overflow
+==10928==ABORTING
+MS: 1 ChangeBinInt-; base unit: c298122410da09836c59484e995c287294c31394
+0x18,0x3,
+\\x18\\x03
+artifact_prefix='./'; Test unit written to ./crash-235641cefe524570bf0df6a3b3722535ce2dbbf7
+Base64: GAM=
+==11002== ERROR: libFuzzer: timeout after 25 seconds
+MS: 0 ; base unit: 0000000000000000000000000000000000000000
+artifact_prefix='out/'; Test unit written to out/timeout-da39a3ee5e6b4b0d3255bfef95601890afd80709
+";
+
+    #[test]
+    fn test_parse_libfuzzer_log() {
+        let artifacts = parse_libfuzzer_log(LOG.as_bytes()).unwrap();
+        assert_eq!(artifacts.len(), 2);
+
+        let crash = &artifacts[0];
+        assert_eq!(crash.kind, ArtifactKind::Crash);
+        assert_eq!(crash.name, "crash-235641cefe524570bf0df6a3b3722535ce2dbbf7");
+        assert_eq!(
+            crash.path,
+            PathBuf::from("./crash-235641cefe524570bf0df6a3b3722535ce2dbbf7")
+        );
+        assert_eq!(
+            crash.hash.to_string(),
+            "235641cefe524570bf0df6a3b3722535ce2dbbf7"
+        );
+        assert_eq!(crash.mutations, "ChangeBinInt-");
+        assert_eq!(
+            crash.base_unit,
+            Some("c298122410da09836c59484e995c287294c31394".to_string())
+        );
+        assert_eq!(crash.line, 9);
+
+        let timeout = &artifacts[1];
+        assert_eq!(timeout.kind, ArtifactKind::Timeout);
+        assert_eq!(timeout.mutations, "");
+        assert_eq!(timeout.base_unit, None);
+
+        let mut graph = parse_libfuzzer_mutation_graph_file(
+            "test/sample/mutation_graph_file/fuzzer-test-suite-openssl-1.0.1f.dot",
+        )
+        .unwrap();
+        for artifact in artifacts.iter() {
+            artifact.add_to(&mut graph);
+        }
+        let predecessors = graph.predecessors_of(&crash.name).unwrap();
+        assert_eq!(
+            predecessors.last(),
+            Some(&&"c298122410da09836c59484e995c287294c31394".to_string())
+        );
+        assert!(graph.get_node(&crash.name).unwrap().crashed);
+        assert!(graph.roots().contains(&timeout.name));
+    }
+}
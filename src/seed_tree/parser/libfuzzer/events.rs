@@ -0,0 +1,173 @@
+use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::parser::diagnostic::{ColumnNumber, LineNumber};
+use crate::seed_tree::parser::error::ParseError;
+use crate::seed_tree::parser::result::Result;
+use regex::Regex;
+use std::io::{BufRead, BufReader, Read};
+
+/// Item of libFuzzer's mutation graph file. Columns are 1-origin and point opening quotes of node names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MutationGraphEvent {
+    Node {
+        name: NodeName,
+        column: ColumnNumber,
+    },
+    Edge {
+        edge: MutationGraphEdge,
+        parent_column: ColumnNumber,
+        child_column: ColumnNumber,
+    },
+    // Line not matching any syntax. Column points first non-whitespace character.
+    UnknownLine {
+        line: String,
+        column: ColumnNumber,
+    },
+    // Last line without newline not matching any syntax (e.g. fuzzer is killed while writing)
+    Truncated {
+        line: String,
+        column: ColumnNumber,
+    },
+}
+
+/// Reads events from libFuzzer's mutation graph line by line without building `MutationGraph`,
+/// so memory usage does not depend on size of input.
+pub struct MutationGraphEvents<T: Read> {
+    reader: BufReader<T>,
+    line: String,
+    line_number: LineNumber,
+    node: Regex,
+    edge: Regex,
+}
+
+impl<T: Read> MutationGraphEvents<T> {
+    pub fn new(reader: T) -> Result<Self> {
+        // Mutation graph file syntax
+        let node = Regex::new("^\\s*\"([\\d[:alpha:]]+)\"\\s*$").map_err(ParseError::RegexError)?;
+        let edge = Regex::new(
+            "^\\s*\"(\\w+)\"\\s*\\->\\s*\"(\\w+)\"\\s*\\[label\\s*=\\s*\"(.*)\"\\]\\s*;\\s*$",
+        )
+        .map_err(ParseError::RegexError)?;
+        Ok(Self {
+            reader: BufReader::new(reader),
+            line: String::new(),
+            line_number: 0,
+            node,
+            edge,
+        })
+    }
+
+    // Parses current line along with above syntax
+    fn event(&self) -> Result<MutationGraphEvent> {
+        let line = &self.line;
+        if let Some(m) = self.node.captures(line) {
+            return match m.get(1) {
+                // NOTE: Node names are ASCII, so byte offset of name is column of opening quote
+                Some(name) => Ok(MutationGraphEvent::Node {
+                    name: name.as_str().to_string(),
+                    column: name.start(),
+                }),
+                None => Err(ParseError::SyntaxError(
+                    "Missing node value",
+                    m[0].to_string(),
+                )),
+            };
+        }
+        if let Some(m) = self.edge.captures(line) {
+            return match (m.get(1), m.get(2), m.get(3)) {
+                (Some(parent), Some(child), Some(label)) => Ok(MutationGraphEvent::Edge {
+                    edge: MutationGraphEdge {
+                        parent: parent.as_str().to_string(),
+                        child: child.as_str().to_string(),
+                        label: label.as_str().to_string(),
+                    },
+                    parent_column: parent.start(),
+                    child_column: child.start(),
+                }),
+                _ => Err(ParseError::SyntaxError(
+                    "Unexpected edge node",
+                    m[0].to_string(),
+                )),
+            };
+        }
+
+        let column = line.chars().take_while(|c| c.is_whitespace()).count() + 1;
+        // NOTE: Last line without newline is partially written one
+        if line.ends_with('\n') {
+            Ok(MutationGraphEvent::UnknownLine {
+                line: line.clone(),
+                column,
+            })
+        } else {
+            Ok(MutationGraphEvent::Truncated {
+                line: line.clone(),
+                column,
+            })
+        }
+    }
+}
+
+impl<T: Read> Iterator for MutationGraphEvents<T> {
+    /// Event with its line number. Errors are annotated with line number.
+    type Item = Result<(LineNumber, MutationGraphEvent)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.line.clear();
+        self.line_number += 1;
+        match self.reader.read_line(&mut self.line) {
+            Ok(0) => None, // reached EOF
+            Ok(_) => Some(
+                self.event()
+                    .map(|event| (self.line_number, event))
+                    .map_err(|why| why.at_line(self.line_number)),
+            ),
+            Err(why) => Some(Err(ParseError::from(why).at_line(self.line_number))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+    use crate::seed_tree::parser::libfuzzer::events::{MutationGraphEvent, MutationGraphEvents};
+
+    #[test]
+    fn test_mutation_graph_events() {
+        let text = "\"a\"\n  \"a\" -> \"b\" [label=\"X\"];\n\"a\" -> \"c\" [lab";
+        let events: Vec<_> = MutationGraphEvents::new(text.as_bytes())
+            .unwrap()
+            .map(|v| v.unwrap())
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                (
+                    1,
+                    MutationGraphEvent::Node {
+                        name: "a".to_string(),
+                        column: 1
+                    }
+                ),
+                (
+                    2,
+                    MutationGraphEvent::Edge {
+                        edge: MutationGraphEdge {
+                            parent: "a".to_string(),
+                            child: "b".to_string(),
+                            label: "X".to_string(),
+                        },
+                        parent_column: 3,
+                        child_column: 10,
+                    }
+                ),
+                (
+                    3,
+                    MutationGraphEvent::Truncated {
+                        line: "\"a\" -> \"c\" [lab".to_string(),
+                        column: 1
+                    }
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,309 @@
+pub mod artifact;
+pub mod events;
+pub mod status;
+
+use self::events::{MutationGraphEvent, MutationGraphEvents};
+use super::result::Result;
+use crate::seed_tree::directed_edge::DirectedEdge;
+use crate::seed_tree::mutation_graph_node::MutationGraphNode;
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::parser::diagnostic::{ColumnNumber, Diagnostic, DiagnosticKind, LineNumber};
+use crate::seed_tree::parser::error::ParseError;
+use crate::seed_tree::MutationGraph;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+pub fn parse_libfuzzer_mutation_graph_file<T: AsRef<Path>>(file: T) -> Result<MutationGraph> {
+    let reader = open(file.as_ref())?;
+    parse_libfuzzer_mutation_graph(reader).map_err(|why| why.in_file(file))
+}
+
+/// Parses libFuzzer's mutation graph read from `reader`. Errors are annotated with line number.
+/// Use `MutationGraphEvents` to process huge files without building whole graph.
+/// Truncated last line (e.g. fuzzer is killed while writing) is skipped with warning.
+pub fn parse_libfuzzer_mutation_graph<T: Read>(reader: T) -> Result<MutationGraph> {
+    let mut graph = MutationGraph::new();
+    parse(reader, &mut graph, None, None)?;
+    Ok(graph)
+}
+
+/// Parses mutation graph files written by jobs of libFuzzer's fork mode (`-fork=N`) or merge (`-merge=1`) into one graph.
+/// Nodes are deduplicated by their names (SHA-1 of inputs) and each edge records paths of files it comes from as jobs.
+pub fn parse_libfuzzer_mutation_graph_files<T: AsRef<Path>>(files: &[T]) -> Result<MutationGraph> {
+    let mut graph = MutationGraph::new();
+    for file in files.iter() {
+        let file = file.as_ref();
+        let reader = open(file)?;
+        // NOTE: Single file does not need jobs
+        let job = match files.len() {
+            1 => None,
+            _ => Some(file.display().to_string()),
+        };
+        parse(reader, &mut graph, job.as_deref(), None).map_err(|why| why.in_file(file))?;
+    }
+    Ok(graph)
+}
+
+/// Streams events of libFuzzer's mutation graph file. See `MutationGraphEvents`.
+pub fn libfuzzer_mutation_graph_file_events<T: AsRef<Path>>(
+    file: T,
+) -> Result<MutationGraphEvents<File>> {
+    MutationGraphEvents::new(open(file.as_ref())?)
+}
+
+pub fn parse_libfuzzer_mutation_graph_file_lenient<T: AsRef<Path>>(
+    file: T,
+) -> Result<(MutationGraph, Vec<Diagnostic>)> {
+    let reader = open(file.as_ref())?;
+    parse_libfuzzer_mutation_graph_lenient(reader).map_err(|why| why.in_file(file))
+}
+
+/// Parses libFuzzer's mutation graph skipping unknown lines instead of aborting.
+/// Returns problems found in input as diagnostics ordered by position. Only I/O errors fail.
+pub fn parse_libfuzzer_mutation_graph_lenient<T: Read>(
+    reader: T,
+) -> Result<(MutationGraph, Vec<Diagnostic>)> {
+    let mut graph = MutationGraph::new();
+    let mut diagnostics = Vec::new();
+    parse(reader, &mut graph, None, Some(&mut diagnostics))?;
+    Ok((graph, diagnostics))
+}
+
+fn open(file: &Path) -> Result<File> {
+    if file.is_dir() {
+        return Err(ParseError::UnexpectedDirectoryPath(file.to_path_buf()));
+    }
+    File::open(file).map_err(|why| ParseError::from(why).in_file(file))
+}
+
+// Collects diagnostics of lenient mode
+struct Linter<'a> {
+    diagnostics: &'a mut Vec<Diagnostic>,
+    declared: HashMap<NodeName, LineNumber>,
+    // Position of first reference by edges
+    referred: HashMap<NodeName, (LineNumber, ColumnNumber)>,
+}
+
+impl<'a> Linter<'a> {
+    fn report(&mut self, line: LineNumber, column: ColumnNumber, kind: DiagnosticKind) {
+        self.diagnostics.push(Diagnostic { line, column, kind })
+    }
+
+    fn declare(&mut self, name: &NodeName, line: LineNumber, column: ColumnNumber) {
+        match self.declared.get(name) {
+            Some(first) => {
+                let first = *first;
+                self.report(
+                    line,
+                    column,
+                    DiagnosticKind::DuplicateNode(name.clone(), first),
+                )
+            }
+            None => {
+                self.declared.insert(name.clone(), line);
+            }
+        }
+    }
+
+    fn refer(&mut self, name: &NodeName, line: LineNumber, column: ColumnNumber) {
+        self.referred.entry(name.clone()).or_insert((line, column));
+    }
+
+    fn finish(mut self) {
+        let mut undeclared: Vec<(LineNumber, ColumnNumber, NodeName)> = self
+            .referred
+            .iter()
+            .filter(|(name, _)| !self.declared.contains_key(*name))
+            .map(|(name, (line, column))| (*line, *column, name.clone()))
+            .collect();
+        undeclared.sort();
+        for (line, column, name) in undeclared {
+            self.report(line, column, DiagnosticKind::UndeclaredNode(name));
+        }
+        // NOTE: Stable sort keeps order of diagnostics found at same position
+        self.diagnostics.sort_by_key(|v| (v.line, v.column));
+    }
+}
+
+// Parses mutation graph into `graph` in strict mode if `diagnostics` is None, otherwise in lenient mode.
+// Edges record `job` if given.
+fn parse<T: Read>(
+    reader: T,
+    graph: &mut MutationGraph,
+    job: Option<&str>,
+    diagnostics: Option<&mut Vec<Diagnostic>>,
+) -> Result<()> {
+    let mut linter = diagnostics.map(|diagnostics| Linter {
+        diagnostics,
+        declared: HashMap::new(),
+        referred: HashMap::new(),
+    });
+
+    for event in MutationGraphEvents::new(reader)? {
+        let (line_number, event) = event?;
+        match (event, linter.as_mut()) {
+            (MutationGraphEvent::Node { name, column }, linter) => {
+                if let Some(linter) = linter {
+                    linter.declare(&name, line_number, column);
+                }
+                graph.add_node(&MutationGraphNode::new(&name))
+            }
+            (
+                MutationGraphEvent::Edge {
+                    edge,
+                    parent_column,
+                    child_column,
+                },
+                Some(linter),
+            ) => {
+                linter.refer(&edge.parent, line_number, parent_column);
+                linter.refer(&edge.child, line_number, child_column);
+                let arrow = DirectedEdge::from(&edge);
+                let existed = graph.get_edge(&arrow).is_some();
+                graph.add_edge(&edge);
+                let kind = if edge.parent == edge.child {
+                    DiagnosticKind::SelfLoop(edge)
+                } else if existed {
+                    DiagnosticKind::DuplicateEdge(edge)
+                } else if graph.get_edge(&arrow).is_none() {
+                    DiagnosticKind::WeakEdge(edge)
+                } else {
+                    continue;
+                };
+                linter.report(line_number, parent_column, kind)
+            }
+            (MutationGraphEvent::Edge { edge, .. }, None) => match job {
+                Some(job) => graph.add_edge_of_job(&edge, job),
+                None => graph.add_edge(&edge),
+            },
+            (MutationGraphEvent::UnknownLine { line, column }, Some(linter)) => {
+                linter.report(line_number, column, DiagnosticKind::UnknownLine(line))
+            }
+            (MutationGraphEvent::UnknownLine { line, .. }, None) => {
+                return Err(ParseError::UnknownLine(line).at_line(line_number))
+            }
+            (MutationGraphEvent::Truncated { line, column }, Some(linter)) => {
+                linter.report(line_number, column, DiagnosticKind::Truncated(line))
+            }
+            (MutationGraphEvent::Truncated { line, .. }, None) => {
+                log::warn!("Skipped truncated line {}: {:?}", line_number, line)
+            }
+        }
+    }
+
+    if let Some(linter) = linter {
+        linter.finish();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::directed_edge::DirectedEdge;
+    use crate::seed_tree::node_name::NodeName;
+    use crate::seed_tree::parser::diagnostic::DiagnosticKind;
+    use crate::seed_tree::parser::libfuzzer::{
+        parse, parse_libfuzzer_mutation_graph, parse_libfuzzer_mutation_graph_file,
+        parse_libfuzzer_mutation_graph_file_lenient, parse_libfuzzer_mutation_graph_files,
+        parse_libfuzzer_mutation_graph_lenient,
+    };
+    use crate::seed_tree::MutationGraph;
+
+    const GRAPH_1: &str = "test/sample/mutation_graph_file/graph1.dot";
+    const OPENSSL: &str = "test/sample/mutation_graph_file/fuzzer-test-suite-openssl-1.0.1f.dot";
+
+    #[test]
+    fn test_parse_files_of_jobs() {
+        let graph = parse_libfuzzer_mutation_graph_files(&[GRAPH_1, OPENSSL]).unwrap();
+        let graph_1 = parse_libfuzzer_mutation_graph_file(GRAPH_1).unwrap();
+        let openssl = parse_libfuzzer_mutation_graph_file(OPENSSL).unwrap();
+
+        // Both jobs start from same seed
+        let root = NodeName::from("adc83b19e793491b1c6ea0fd8b46cd9f32e592fc");
+        assert!(graph_1.get_node(&root).is_some() && openssl.get_node(&root).is_some());
+        assert_eq!(
+            graph.node_count(),
+            graph_1.node_count() + openssl.node_count() - 1
+        );
+        assert_eq!(graph.jobs(), [GRAPH_1, OPENSSL]);
+        assert_eq!(
+            graph.jobs_of(&DirectedEdge::new(
+                &root,
+                &NodeName::from("a2dfa9429bf2a04d8f23fe980209bd5315f80523")
+            )),
+            vec![GRAPH_1]
+        );
+        assert_eq!(
+            graph.jobs_of(&DirectedEdge::new(
+                &root,
+                &NodeName::from("c5c050e132b1ee3a4f627b3b0350b77737f5f181")
+            )),
+            vec![OPENSSL]
+        );
+
+        // Single file does not record jobs
+        assert!(graph_1.jobs().is_empty());
+
+        // Edge declared by both jobs is a tree edge of both, not a duplicate
+        let mut graph = MutationGraph::new();
+        let text = "\"a\"\n\"b\"\n\"a\" -> \"b\" [label=\"X\"];\n";
+        parse(text.as_bytes(), &mut graph, Some("job-1"), None).unwrap();
+        parse(text.as_bytes(), &mut graph, Some("job-2"), None).unwrap();
+        let edge = DirectedEdge::new(&NodeName::from("a"), &NodeName::from("b"));
+        assert!(graph.get_edge(&edge).is_some());
+        assert!(graph.weak_edges().is_empty());
+        assert_eq!(graph.jobs_of(&edge), vec!["job-1", "job-2"]);
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let (graph, diagnostics) = parse_libfuzzer_mutation_graph_file_lenient(OPENSSL).unwrap();
+        assert!(graph.node_count() > 0);
+        let positions: Vec<_> = diagnostics
+            .iter()
+            .take(2)
+            .map(|v| (v.line, v.column, v.kind.name()))
+            .collect();
+        assert_eq!(
+            positions,
+            vec![(2, 1, "duplicate-node"), (3, 1, "self-loop")]
+        );
+    }
+
+    #[test]
+    fn test_parse_truncated() {
+        let text = "\"a\"\n\"b\"\n\"a\" -> \"b\" [label=\"X\"];\n  bogus\n\"a\" -> \"c\" [label=\"Y\"];\n\"b\" -> \"a\" [label=\"Z\"];\n\"b\" -> \"d\" [lab";
+
+        // Strict mode rejects unknown line but skips truncated last line
+        assert_eq!(
+            parse_libfuzzer_mutation_graph(text.as_bytes())
+                .unwrap_err()
+                .line(),
+            Some(4)
+        );
+        let without_unknown_line = text.replace("  bogus\n", "");
+        let graph = parse_libfuzzer_mutation_graph(without_unknown_line.as_bytes()).unwrap();
+        assert_eq!(graph.node_count(), 3);
+        assert!(graph.get_node(&NodeName::from("d")).is_none());
+
+        let (graph, diagnostics) = parse_libfuzzer_mutation_graph_lenient(text.as_bytes()).unwrap();
+        assert_eq!(graph.node_count(), 3);
+        let found: Vec<_> = diagnostics
+            .iter()
+            .map(|v| (v.line, v.column, v.kind.clone()))
+            .collect();
+        assert_eq!(found.len(), 4);
+        assert_eq!(
+            found[0],
+            (4, 3, DiagnosticKind::UnknownLine("  bogus\n".to_string()))
+        );
+        assert_eq!(
+            found[1],
+            (5, 8, DiagnosticKind::UndeclaredNode("c".to_string()))
+        );
+        assert_eq!((found[2].0, found[2].2.name()), (6, "weak-edge"));
+        assert_eq!((found[3].0, found[3].2.name()), (7, "truncated"));
+    }
+}
@@ -0,0 +1,243 @@
+use crate::seed_tree::discovery_stats::DiscoveryStats;
+use crate::seed_tree::node_id::NodeId;
+use crate::seed_tree::parser::diagnostic::LineNumber;
+use crate::seed_tree::parser::error::ParseError;
+use crate::seed_tree::parser::result::Result;
+use crate::seed_tree::MutationGraph;
+use regex::Regex;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+// Number of nodes looked ahead for the node discovered at a `NEW` line.
+// NOTE: `NEW` lines do not name units, and some of them do not declare new nodes (e.g. units same as existing ones)
+const LOOKAHEAD: usize = 8;
+
+/// Event of a status line of libFuzzer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusEvent {
+    Inited,
+    New,
+    Reduce,
+    Pulse,
+    Done,
+    Reload,
+}
+
+impl StatusEvent {
+    fn parse(event: &str) -> Option<Self> {
+        match event {
+            "INITED" => Some(Self::Inited),
+            "NEW" => Some(Self::New),
+            "REDUCE" => Some(Self::Reduce),
+            "pulse" => Some(Self::Pulse),
+            "DONE" => Some(Self::Done),
+            "RELOAD" => Some(Self::Reload),
+            _ => None,
+        }
+    }
+}
+
+/// Status line of libFuzzer's log (e.g. `#1234 NEW    cov: 812 ft: 2011 corp: 45/3Kb ... MS: 3 ChangeBit-CopyPart-InsertByte-`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusLine {
+    pub event: StatusEvent,
+    pub execs: u64,
+    pub cov: u64,
+    pub ft: u64,
+    pub corpus_units: u64,
+    /// NOTE: libFuzzer rounds sizes down to Kb or Mb
+    pub corpus_bytes: u64,
+    /// Mutation sequence in the same form as labels of mutation graph (e.g. `ChangeBit-CopyPart-`)
+    pub mutations: Option<String>,
+    pub line: LineNumber,
+}
+
+pub fn parse_libfuzzer_status_log_file<T: AsRef<Path>>(file: T) -> Result<Vec<StatusLine>> {
+    let file = file.as_ref();
+    let reader = File::open(file).map_err(|why| ParseError::from(why).in_file(file))?;
+    parse_libfuzzer_status_log(reader).map_err(|why| why.in_file(file))
+}
+
+/// Extracts status lines from libFuzzer's log (i.e. stderr) in order. Other lines are ignored.
+pub fn parse_libfuzzer_status_log<T: Read>(reader: T) -> Result<Vec<StatusLine>> {
+    let status = Regex::new(
+        "^#(\\d+)\\s+(\\w+)\\s+cov: (\\d+)(?: ft: (\\d+))? corp: (\\d+)/(\\d+)(b|Kb|Mb)(?:.* MS: \\d+ (\\S*))?",
+    )
+    .map_err(ParseError::RegexError)?;
+
+    let mut lines = Vec::new();
+    let mut reader = BufReader::new(reader);
+    let mut line_number = 0;
+    loop {
+        line_number += 1;
+        // NOTE: Logs may contain outputs of target program which are not valid UTF-8
+        let mut bytes = Vec::new();
+        let length = reader
+            .read_until(b'\n', &mut bytes)
+            .map_err(|why| ParseError::from(why).at_line(line_number))?;
+        if length == 0 {
+            // reached EOF
+            break;
+        }
+        let line = String::from_utf8_lossy(&bytes);
+        let m = match status.captures(&line) {
+            Some(m) => m,
+            None => continue,
+        };
+        let event = match StatusEvent::parse(&m[2]) {
+            Some(event) => event,
+            None => continue,
+        };
+        let number = |index: usize| -> Result<u64> {
+            match m.get(index) {
+                Some(v) => v.as_str().parse().map_err(|_| {
+                    ParseError::SyntaxError("Too large number", m[0].to_string())
+                        .at_line(line_number)
+                }),
+                None => Ok(0),
+            }
+        };
+        let unit = match &m[7] {
+            "Kb" => 1 << 10,
+            "Mb" => 1 << 20,
+            _ => 1,
+        };
+        lines.push(StatusLine {
+            event,
+            execs: number(1)?,
+            cov: number(3)?,
+            ft: number(4)?,
+            corpus_units: number(5)?,
+            corpus_bytes: number(6)? * unit,
+            mutations: m.get(8).map(|v| v.as_str().to_string()),
+            line: line_number,
+        });
+    }
+    Ok(lines)
+}
+
+/// Sets stats of nodes discovered at `NEW` lines of `lines`. Returns number of aligned nodes.
+///
+/// Mutation graph file does not tell which line discovered which node, so `NEW` lines are aligned with
+/// non-root nodes in order of declaration, checking their mutation sequences with labels of edges to the nodes.
+/// `NEW` lines without matching node are skipped.
+pub fn align_status_lines(lines: &[StatusLine], graph: &mut MutationGraph) -> usize {
+    let discovered: Vec<NodeId> = (0..graph.node_count())
+        .map(NodeId::from)
+        .filter(|id| graph.parent[id.index()].is_some())
+        .collect();
+
+    let mut aligned = 0;
+    let mut next = 0;
+    let mut previous: Option<&StatusLine> = None;
+    for line in lines.iter() {
+        let gain =
+            |current: u64, previous: Option<u64>| current.saturating_sub(previous.unwrap_or(0));
+        let cov_gain = gain(line.cov, previous.map(|v| v.cov));
+        let ft_gain = gain(line.ft, previous.map(|v| v.ft));
+        previous = Some(line);
+        if line.event != StatusEvent::New {
+            continue;
+        }
+
+        let found = discovered[next..].iter().take(LOOKAHEAD).position(|id| {
+            match (graph.parent[id.index()], &line.mutations) {
+                (Some((_, label)), Some(mutations)) => graph.label[label.index()] == *mutations,
+                _ => false,
+            }
+        });
+        let id = match found {
+            Some(offset) => {
+                next += offset + 1;
+                discovered[next - 1]
+            }
+            None => {
+                log::debug!("No node matches NEW line at line {}", line.line);
+                continue;
+            }
+        };
+        graph.node[id.index()].stats = Some(DiscoveryStats {
+            execs: line.execs,
+            cov: line.cov,
+            ft: line.ft,
+            cov_gain,
+            ft_gain,
+            corpus_units: line.corpus_units,
+            corpus_bytes: line.corpus_bytes,
+        });
+        aligned += 1;
+    }
+    aligned
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+    use crate::seed_tree::mutation_graph_node::MutationGraphNode;
+    use crate::seed_tree::node_name::NodeName;
+    use crate::seed_tree::parser::libfuzzer::status::{
+        align_status_lines, parse_libfuzzer_status_log, StatusEvent,
+    };
+    use crate::seed_tree::MutationGraph;
+
+    const LOG: &str = "\
+INFO: Seed: 3918206239
+#2\tINITED cov: 3 ft: 4 corp: 1/1b exec/s: 0 rss: 28Mb
+#7\tNEW    cov: 5 ft: 6 corp: 2/3b lim: 4 exec/s: 0 rss: 28Mb L: 2/2 MS: 2 ChangeBit-InsertByte-
+#9\tNEW    cov: 5 ft: 7 corp: 3/5b lim: 4 exec/s: 0 rss: 28Mb L: 2/2 MS: 1 ShuffleBytes-
+#12\tREDUCE cov: 5 ft: 7 corp: 3/4b lim: 4 exec/s: 0 rss: 28Mb L: 1/2 MS: 1 EraseBytes-
+#20\tNEW    cov: 9 ft: 12 corp: 4/17Kb lim: 4 exec/s: 0 rss: 28Mb L: 3/3 MS: 2 CopyPart-CMP- DE: \"\\x00\\x01\"-
+#4096\tpulse  cov: 9 ft: 12 corp: 4/17Kb lim: 43 exec/s: 2048 rss: 29Mb
+";
+
+    #[test]
+    fn test_parse_libfuzzer_status_log() {
+        let lines = parse_libfuzzer_status_log(LOG.as_bytes()).unwrap();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[0].event, StatusEvent::Inited);
+        assert_eq!(lines[0].mutations, None);
+
+        let line = &lines[4];
+        assert_eq!(line.event, StatusEvent::New);
+        assert_eq!(line.execs, 20);
+        assert_eq!((line.cov, line.ft), (9, 12));
+        assert_eq!((line.corpus_units, line.corpus_bytes), (4, 17 << 10));
+        assert_eq!(line.mutations, Some("CopyPart-CMP-".to_string()));
+        assert_eq!(line.line, 6);
+    }
+
+    #[test]
+    fn test_align_status_lines() {
+        let seed = NodeName::from("seed");
+        let a = NodeName::from("a");
+        let b = NodeName::from("b");
+        let c = NodeName::from("c");
+        let mut graph = MutationGraph::new();
+        graph.add_node(&MutationGraphNode::new(&seed));
+        for (parent, child, label) in [
+            (&seed, &a, "ChangeBit-InsertByte-"),
+            // NOTE: Unit same as its parent is not a new node
+            (&a, &a, "ShuffleBytes-"),
+            (&a, &b, "CopyPart-CMP-"),
+            (&b, &c, "CrossOver-"),
+        ]
+        .iter()
+        {
+            graph.add_edge(&MutationGraphEdge {
+                parent: (*parent).clone(),
+                child: (*child).clone(),
+                label: label.to_string(),
+            });
+        }
+
+        let lines = parse_libfuzzer_status_log(LOG.as_bytes()).unwrap();
+        assert_eq!(align_status_lines(&lines, &mut graph), 2);
+        assert_eq!(graph.get_node(&seed).unwrap().stats, None);
+        let stats = graph.get_node(&a).unwrap().stats.unwrap();
+        assert_eq!((stats.execs, stats.cov_gain, stats.ft_gain), (7, 2, 2));
+        let stats = graph.get_node(&b).unwrap().stats.unwrap();
+        assert_eq!((stats.execs, stats.cov_gain, stats.ft_gain), (20, 4, 5));
+        assert_eq!(graph.get_node(&c).unwrap().stats, None);
+    }
+}
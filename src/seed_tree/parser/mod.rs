@@ -1,9 +1,11 @@
+pub mod diagnostic;
 pub mod error;
 pub mod result;
 
 pub mod generic;
+pub mod honggfuzz;
+pub mod libafl;
 
-#[cfg(feature = "afl")]
 pub mod afl;
-#[cfg(feature = "libfuzzer")]
+pub mod afl_stats;
 pub mod libfuzzer;
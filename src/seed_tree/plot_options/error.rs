@@ -1,7 +1,26 @@
 use crate::seed_tree::node_name::NodeName;
 use std::collections::HashSet;
+use std::fmt;
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum PlotOptionError {
     MultiplePredecessorsNotSupported(HashSet<NodeName>),
 }
+
+impl fmt::Display for PlotOptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MultiplePredecessorsNotSupported(names) => {
+                let mut names: Vec<&NodeName> = names.iter().collect();
+                names.sort();
+                write!(
+                    f,
+                    "edges from root can be highlighted to only one node: {:?}",
+                    names
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PlotOptionError {}
@@ -9,6 +9,7 @@ pub mod result;
 use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
 use crate::seed_tree::node_name::NodeName;
 use crate::seed_tree::plot_options::error::PlotOptionError;
+use crate::seed_tree::sort_order::SortOrder;
 use result::Result;
 
 type Label = String;
@@ -21,6 +22,7 @@ pub struct PlotOptions {
     pub highlight_edge_with_green: HashSet<MutationGraphEdge>,
     pub highlight_crash_input: bool,
     pub notate: HashMap<NodeName, Label>,
+    pub sort_order: SortOrder,
 }
 
 impl PlotOptions {
@@ -107,6 +109,15 @@ impl PlotOptions {
                 }
                 notes
             },
+            sort_order: {
+                let mut sort_order = SortOrder::default();
+                for option in options.iter() {
+                    if let PlotOption::SortBy(v) = option {
+                        sort_order = *v; // NOTE: Last one wins
+                    }
+                }
+                sort_order
+            },
         })
     }
 }
@@ -117,6 +128,7 @@ mod tests {
     use crate::seed_tree::plot_options::error::PlotOptionError;
     use crate::seed_tree::plot_options::plot_option::PlotOption;
     use crate::seed_tree::plot_options::PlotOptions;
+    use crate::seed_tree::sort_order::SortOrder;
     use std::collections::HashSet;
     use std::iter::FromIterator;
 
@@ -154,4 +166,19 @@ mod tests {
             ))
         )
     }
+
+    #[test]
+    fn test_plot_options_sort_by() {
+        let options = PlotOptions::from(&[
+            PlotOption::SortBy(SortOrder::Id),
+            PlotOption::SortBy(SortOrder::Topological),
+        ]);
+        assert_eq!(
+            options,
+            Ok(PlotOptions {
+                sort_order: SortOrder::Topological,
+                ..Default::default()
+            })
+        )
+    }
 }
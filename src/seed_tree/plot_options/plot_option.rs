@@ -1,5 +1,6 @@
 use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
 use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::sort_order::SortOrder;
 
 type Label = String;
 
@@ -11,4 +12,5 @@ pub enum PlotOption {
     HighlightEdgeWithGreen(MutationGraphEdge),
     HighlightCrashInput,
     NotateTo(NodeName, Label),
+    SortBy(SortOrder),
 }
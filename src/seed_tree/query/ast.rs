@@ -0,0 +1,111 @@
+use crate::seed_tree::node_name::NodeName;
+use regex::Regex;
+
+/// Expression denotes a set of nodes
+#[derive(Debug, Clone)]
+pub enum Expr {
+    All,
+    Roots,
+    Leaves,
+    Crashed,
+    /// Initial seeds given to the fuzzer
+    Seeds,
+    Node(NodeName),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Call(Function, Box<Expr>),
+    Compare(Field, Comparison),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Function {
+    /// Predecessors of nodes excluding themselves
+    Ancestors,
+    /// Successors of nodes excluding themselves
+    Descendants,
+    /// Nodes and their ancestors
+    Lineage,
+    /// Nodes and their descendants
+    Subtree,
+    Children,
+    Parent,
+}
+
+impl Function {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ancestors" | "preds" => Some(Self::Ancestors),
+            "descendants" => Some(Self::Descendants),
+            "lineage" => Some(Self::Lineage),
+            "subtree" => Some(Self::Subtree),
+            "children" => Some(Self::Children),
+            "parent" => Some(Self::Parent),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    /// Rank of node (root is 0)
+    Depth,
+    /// Number of children
+    Children,
+    /// Discovery time of node
+    Time,
+    Name,
+    File,
+    Hash,
+    /// Label of edge from parent (i.e. mutation operator)
+    Op,
+}
+
+impl Field {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "depth" | "rank" => Some(Self::Depth),
+            "children" => Some(Self::Children),
+            "time" => Some(Self::Time),
+            "name" => Some(Self::Name),
+            "file" => Some(Self::File),
+            "hash" => Some(Self::Hash),
+            "op" | "label" => Some(Self::Op),
+            _ => None,
+        }
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, Self::Depth | Self::Children | Self::Time)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Comparison {
+    Number(Comparator, u64),
+    String(Comparator, String),
+    Matches(Regex),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparator {
+    pub fn compare<T: Ord + ?Sized>(&self, left: &T, right: &T) -> bool {
+        match self {
+            Self::Eq => left == right,
+            Self::Ne => left != right,
+            Self::Lt => left < right,
+            Self::Le => left <= right,
+            Self::Gt => left > right,
+            Self::Ge => left >= right,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::seed_tree::node_name::NodeName;
+use std::fmt;
+
+type Position = usize;
+
+#[derive(Debug)]
+pub enum QueryError {
+    UnexpectedCharacter(Position, char),
+    UnterminatedString(Position),
+    UnexpectedToken(Position, String),
+    UnexpectedEnd,
+    UnknownFunction(String),
+    // Field does not accept given comparator or value
+    InvalidComparison(String),
+    RegexError(regex::Error),
+    NodeNotExists(NodeName),
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedCharacter(position, c) => {
+                write!(f, "unexpected character {:?} at {}", c, position)
+            }
+            Self::UnterminatedString(position) => {
+                write!(f, "unterminated string starting at {}", position)
+            }
+            Self::UnexpectedToken(position, token) => {
+                write!(f, "unexpected token {} at {}", token, position)
+            }
+            Self::UnexpectedEnd => write!(f, "unexpected end of query"),
+            Self::UnknownFunction(name) => write!(f, "unknown function {:?}", name),
+            Self::InvalidComparison(value) => write!(f, "invalid comparison with {:?}", value),
+            Self::RegexError(error) => write!(f, "{}", error),
+            Self::NodeNotExists(name) => write!(f, "node {:?} does not exist", name),
+        }
+    }
+}
+
+impl std::error::Error for QueryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::RegexError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<regex::Error> for QueryError {
+    fn from(error: regex::Error) -> Self {
+        Self::RegexError(error)
+    }
+}
@@ -0,0 +1,316 @@
+pub mod ast;
+pub mod error;
+mod parser;
+pub mod result;
+
+use self::ast::{Comparison, Expr, Field, Function};
+use self::error::QueryError;
+use self::result::Result;
+use super::node_id::NodeId;
+use super::node_name::NodeName;
+use super::MutationGraph;
+use std::collections::HashSet;
+
+/// Query selects nodes of a seed tree.
+///
+/// e.g. `crashed && depth > 5 && op ~ "splice"`, `ancestors(crash-000012) | descendants(000310)`
+#[derive(Debug, Clone)]
+pub struct Query {
+    pub expr: Expr,
+}
+
+impl Query {
+    pub fn parse(query: &str) -> Result<Self> {
+        Ok(Self {
+            expr: parser::parse(query)?,
+        })
+    }
+
+    /// Returns names of nodes matched with this query
+    pub fn select<'a>(&self, graph: &'a MutationGraph) -> Result<HashSet<&'a NodeName>> {
+        let evaluator = Evaluator::new(graph);
+        let selected = evaluator.eval(&self.expr)?;
+        Ok(selected
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| **v)
+            .map(|(id, _)| graph.name_of(NodeId::from(id)))
+            .collect())
+    }
+}
+
+// Evaluates expression to set of nodes represented as flags indexed by `NodeId`
+struct Evaluator<'a> {
+    graph: &'a MutationGraph,
+    depth: Vec<u64>,
+}
+
+impl<'a> Evaluator<'a> {
+    fn new(graph: &'a MutationGraph) -> Self {
+        // NOTE: Depth is computed from roots at once rather than walking to root from each node
+        let mut depth = vec![0; graph.node_count()];
+        let mut stack: Vec<NodeId> = (0..graph.node_count())
+            .map(NodeId::from)
+            .filter(|id| graph.parent[id.index()].is_none())
+            .collect();
+        while let Some(id) = stack.pop() {
+            for child in graph.children[id.index()].iter() {
+                depth[child.index()] = depth[id.index()] + 1;
+                stack.push(*child);
+            }
+        }
+        Self { graph, depth }
+    }
+
+    fn eval(&self, expr: &Expr) -> Result<Vec<bool>> {
+        let graph = self.graph;
+        let count = graph.node_count();
+        let res = match expr {
+            Expr::All => vec![true; count],
+            Expr::Roots => graph.parent.iter().map(|v| v.is_none()).collect(),
+            Expr::Leaves => graph.children.iter().map(|v| v.is_empty()).collect(),
+            Expr::Crashed => graph.node.iter().map(|v| v.crashed).collect(),
+            Expr::Seeds => graph.node.iter().map(|v| v.initial_seed).collect(),
+            Expr::Node(name) => match graph.id_of(name) {
+                Some(id) => {
+                    let mut res = vec![false; count];
+                    res[id.index()] = true;
+                    res
+                }
+                None => return Err(QueryError::NodeNotExists(name.clone())),
+            },
+            Expr::Not(expr) => self.eval(expr)?.iter().map(|v| !v).collect(),
+            Expr::And(left, right) => {
+                let right = self.eval(right)?;
+                let mut res = self.eval(left)?;
+                res.iter_mut().zip(right).for_each(|(l, r)| *l &= r);
+                res
+            }
+            Expr::Or(left, right) => {
+                let right = self.eval(right)?;
+                let mut res = self.eval(left)?;
+                res.iter_mut().zip(right).for_each(|(l, r)| *l |= r);
+                res
+            }
+            Expr::Call(function, expr) => self.call(*function, &self.eval(expr)?),
+            Expr::Compare(field, comparison) => (0..count)
+                .map(|id| self.compare(NodeId::from(id), *field, comparison))
+                .collect(),
+        };
+        Ok(res)
+    }
+
+    fn call(&self, function: Function, argument: &[bool]) -> Vec<bool> {
+        let graph = self.graph;
+        let mut res = vec![false; argument.len()];
+        let selected = argument
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| **v)
+            .map(|(id, _)| NodeId::from(id));
+        match function {
+            Function::Ancestors | Function::Lineage => {
+                for id in selected {
+                    if function == Function::Lineage {
+                        res[id.index()] = true;
+                    }
+                    let mut current = id;
+                    // NOTE: Stop walking at visited node since its ancestors are already selected
+                    while let Some(parent) = graph.parent_id_of(current) {
+                        if res[parent.index()] {
+                            break;
+                        }
+                        res[parent.index()] = true;
+                        current = parent;
+                    }
+                }
+            }
+            Function::Descendants | Function::Subtree => {
+                let mut stack = Vec::new();
+                for id in selected {
+                    if function == Function::Subtree {
+                        res[id.index()] = true;
+                    }
+                    stack.push(id);
+                }
+                while let Some(id) = stack.pop() {
+                    for child in graph.children[id.index()].iter() {
+                        if !res[child.index()] {
+                            res[child.index()] = true;
+                            stack.push(*child);
+                        }
+                    }
+                }
+            }
+            Function::Children => {
+                for id in selected {
+                    for child in graph.children[id.index()].iter() {
+                        res[child.index()] = true;
+                    }
+                }
+            }
+            Function::Parent => {
+                for id in selected {
+                    if let Some(parent) = graph.parent_id_of(id) {
+                        res[parent.index()] = true;
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    fn compare(&self, id: NodeId, field: Field, comparison: &Comparison) -> bool {
+        let graph = self.graph;
+        let node = &graph.node[id.index()];
+        let number = match field {
+            Field::Depth => Some(self.depth[id.index()]),
+            Field::Children => Some(graph.children[id.index()].len() as u64),
+            Field::Time => node.discovered,
+            _ => None,
+        };
+        let string = match field {
+            Field::Name => Some(node.name.clone()),
+            Field::File => Some(node.file.display().to_string()),
+            Field::Hash => Some(node.hash.to_string()),
+            Field::Op => {
+                graph.parent[id.index()].map(|(_, label)| graph.label[label.index()].clone())
+            }
+            _ => None,
+        };
+        // NOTE: Nodes without the field (e.g. discovery time is unknown, root does not have op) never match
+        match (comparison, number, string) {
+            (Comparison::Number(comparator, value), Some(number), _) => {
+                comparator.compare(&number, value)
+            }
+            (Comparison::String(comparator, value), _, Some(string)) => {
+                comparator.compare(&string, value)
+            }
+            (Comparison::Matches(regex), _, Some(string)) => regex.is_match(&string),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+    use crate::seed_tree::mutation_graph_node::MutationGraphNode;
+    use crate::seed_tree::node_name::NodeName;
+    use crate::seed_tree::query::error::QueryError;
+    use crate::seed_tree::query::Query;
+    use crate::seed_tree::MutationGraph;
+
+    //   0 -(havoc)-> 1 -(splice)-> 2 (crashed)
+    //   0 -(havoc)-> 3
+    //   4
+    fn sample_graph() -> MutationGraph {
+        let mut graph = MutationGraph::new();
+        for name in ["0", "1", "2", "3", "4"].iter() {
+            let mut node = MutationGraphNode::new(&name.to_string());
+            node.crashed = *name == "2";
+            node.initial_seed = *name == "0";
+            node.discovered = Some(name.parse::<u64>().unwrap() * 1000);
+            graph.add_node(&node);
+        }
+        for (parent, child, label) in [
+            ("0", "1", "havoc"),
+            ("1", "2", "splice"),
+            ("0", "3", "havoc"),
+        ]
+        .iter()
+        {
+            graph.add_edge(&MutationGraphEdge {
+                parent: parent.to_string(),
+                child: child.to_string(),
+                label: label.to_string(),
+            });
+        }
+        graph
+    }
+
+    fn select(graph: &MutationGraph, query: &str) -> Vec<NodeName> {
+        let mut res: Vec<NodeName> = Query::parse(query)
+            .unwrap()
+            .select(graph)
+            .unwrap()
+            .into_iter()
+            .cloned()
+            .collect();
+        res.sort();
+        res
+    }
+
+    #[test]
+    fn test_query_select() {
+        let graph = sample_graph();
+        assert_eq!(select(&graph, "all").len(), 5);
+        assert_eq!(select(&graph, "roots"), vec!["0", "4"]);
+        assert_eq!(select(&graph, "leaves"), vec!["2", "3", "4"]);
+        assert_eq!(select(&graph, "crashed"), vec!["2"]);
+        assert_eq!(select(&graph, "subtree(seeds) && crashed"), vec!["2"]);
+        assert_eq!(select(&graph, "!crashed && leaves"), vec!["3", "4"]);
+        assert_eq!(select(&graph, "depth >= 1"), vec!["1", "2", "3"]);
+        assert_eq!(select(&graph, "rank == 2 || \"4\""), vec!["2", "4"]);
+        assert_eq!(select(&graph, "children > 0"), vec!["0", "1"]);
+        assert_eq!(select(&graph, "time < 2000"), vec!["0", "1"]);
+        assert_eq!(select(&graph, "op ~ \"spl\""), vec!["2"]);
+        assert_eq!(select(&graph, "op != havoc"), vec!["2"]);
+        assert_eq!(select(&graph, "name ~ \"^[12]$\""), vec!["1", "2"]);
+        assert_eq!(select(&graph, "ancestors(2)"), vec!["0", "1"]);
+        assert_eq!(select(&graph, "lineage(crashed)"), vec!["0", "1", "2"]);
+        assert_eq!(select(&graph, "descendants(0)"), vec!["1", "2", "3"]);
+        assert_eq!(select(&graph, "subtree(1) | 4"), vec!["1", "2", "4"]);
+        assert_eq!(select(&graph, "children(0)"), vec!["1", "3"]);
+        assert_eq!(select(&graph, "parent(leaves)"), vec!["0", "1"]);
+        assert_eq!(
+            select(&graph, "crashed & (depth > 5 | op ~ splice)"),
+            vec!["2"]
+        );
+    }
+
+    #[test]
+    fn test_query_error() {
+        let graph = sample_graph();
+        assert!(matches!(
+            Query::parse("crashed &&"),
+            Err(QueryError::UnexpectedEnd)
+        ));
+        assert!(matches!(
+            Query::parse("(crashed"),
+            Err(QueryError::UnexpectedEnd)
+        ));
+        assert!(matches!(
+            Query::parse("crashed )"),
+            Err(QueryError::UnexpectedToken(8, _))
+        ));
+        assert!(matches!(
+            Query::parse("\"crashed"),
+            Err(QueryError::UnterminatedString(0))
+        ));
+        assert!(matches!(
+            Query::parse("crashed $"),
+            Err(QueryError::UnexpectedCharacter(8, '$'))
+        ));
+        assert!(matches!(
+            Query::parse("unknown(0)"),
+            Err(QueryError::UnknownFunction(_))
+        ));
+        assert!(matches!(
+            Query::parse("depth > deep"),
+            Err(QueryError::InvalidComparison(_))
+        ));
+        assert!(matches!(
+            Query::parse("depth ~ 1"),
+            Err(QueryError::InvalidComparison(_))
+        ));
+        assert!(matches!(
+            Query::parse("op ~ \"(\""),
+            Err(QueryError::RegexError(_))
+        ));
+        assert!(matches!(
+            Query::parse("ancestors(5)").unwrap().select(&graph),
+            Err(QueryError::NodeNotExists(_))
+        ));
+    }
+}
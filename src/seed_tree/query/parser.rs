@@ -0,0 +1,231 @@
+use super::ast::{Comparator, Comparison, Expr, Field, Function};
+use super::error::QueryError;
+use super::result::Result;
+use regex::Regex;
+
+// Grammar of query:
+//
+//   expr       := and (("||" | "|") and)*
+//   and        := unary (("&&" | "&") unary)*
+//   unary      := "!" unary | primary
+//   primary    := "(" expr ")" | FUNCTION "(" expr ")" | FIELD COMPARATOR value | KEYWORD | node
+//   comparator := "==" | "!=" | "<" | "<=" | ">" | ">=" | "~"
+//
+// Bare words not being keywords are node names. Quote node names to use names same as keywords.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    LeftParen,
+    RightParen,
+    Not,
+    And,
+    Or,
+    Comparator(Comparator),
+    Match,
+    Word(String),
+    Quoted(String),
+}
+
+type Position = usize;
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || "_-.:+,/@".contains(c)
+}
+
+fn tokenize(query: &str) -> Result<Vec<(Position, Token)>> {
+    let chars: Vec<(Position, char)> = query.char_indices().collect();
+    let peek = |i: usize| chars.get(i).map(|v| v.1);
+
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while let Some(c) = peek(i) {
+        let position = chars[i].0;
+        let (token, length) = match (c, peek(i + 1)) {
+            (c, _) if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            ('(', _) => (Token::LeftParen, 1),
+            (')', _) => (Token::RightParen, 1),
+            ('&', Some('&')) | ('|', Some('|')) => {
+                (if c == '&' { Token::And } else { Token::Or }, 2)
+            }
+            ('&', _) => (Token::And, 1),
+            ('|', _) => (Token::Or, 1),
+            ('=', Some('=')) => (Token::Comparator(Comparator::Eq), 2),
+            ('!', Some('=')) => (Token::Comparator(Comparator::Ne), 2),
+            ('<', Some('=')) => (Token::Comparator(Comparator::Le), 2),
+            ('>', Some('=')) => (Token::Comparator(Comparator::Ge), 2),
+            ('<', _) => (Token::Comparator(Comparator::Lt), 1),
+            ('>', _) => (Token::Comparator(Comparator::Gt), 1),
+            ('!', _) => (Token::Not, 1),
+            ('~', _) => (Token::Match, 1),
+            ('"', _) => {
+                let mut value = String::new();
+                let mut j = i + 1;
+                loop {
+                    match peek(j) {
+                        Some('"') => break,
+                        Some('\\') if peek(j + 1).is_some() => {
+                            value.push(peek(j + 1).unwrap());
+                            j += 2;
+                        }
+                        Some(c) => {
+                            value.push(c);
+                            j += 1;
+                        }
+                        None => return Err(QueryError::UnterminatedString(position)),
+                    }
+                }
+                (Token::Quoted(value), j + 1 - i)
+            }
+            (c, _) if is_word_char(c) => {
+                let mut j = i;
+                while matches!(peek(j), Some(c) if is_word_char(c)) {
+                    j += 1;
+                }
+                let word = chars[i..j].iter().map(|v| v.1).collect();
+                (Token::Word(word), j - i)
+            }
+            (c, _) => return Err(QueryError::UnexpectedCharacter(position, c)),
+        };
+        tokens.push((position, token));
+        i += length;
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Position, Token)>,
+    next: usize,
+}
+
+impl Parser {
+    fn peek(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.next + offset).map(|v| &v.1)
+    }
+
+    fn consume(&mut self) -> Result<Token> {
+        match self.tokens.get(self.next) {
+            Some((_, token)) => {
+                self.next += 1;
+                Ok(token.clone())
+            }
+            None => Err(QueryError::UnexpectedEnd),
+        }
+    }
+
+    fn unexpected(&self) -> QueryError {
+        match self.tokens.get(self.next) {
+            Some((position, token)) => {
+                QueryError::UnexpectedToken(*position, format!("{:?}", token))
+            }
+            None => QueryError::UnexpectedEnd,
+        }
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<()> {
+        if self.peek(0) == Some(&expected) {
+            self.next += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn expr(&mut self) -> Result<Expr> {
+        let mut left = self.and()?;
+        while self.peek(0) == Some(&Token::Or) {
+            self.next += 1;
+            left = Expr::Or(Box::new(left), Box::new(self.and()?));
+        }
+        Ok(left)
+    }
+
+    fn and(&mut self) -> Result<Expr> {
+        let mut left = self.unary()?;
+        while self.peek(0) == Some(&Token::And) {
+            self.next += 1;
+            left = Expr::And(Box::new(left), Box::new(self.unary()?));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expr> {
+        if self.peek(0) == Some(&Token::Not) {
+            self.next += 1;
+            Ok(Expr::Not(Box::new(self.unary()?)))
+        } else {
+            self.primary()
+        }
+    }
+
+    fn primary(&mut self) -> Result<Expr> {
+        match self.consume()? {
+            Token::LeftParen => {
+                let expr = self.expr()?;
+                self.expect(Token::RightParen)?;
+                Ok(expr)
+            }
+            Token::Quoted(name) => Ok(Expr::Node(name)),
+            Token::Word(word) => match self.peek(0) {
+                Some(Token::LeftParen) => {
+                    let function = Function::from_name(&word)
+                        .ok_or_else(|| QueryError::UnknownFunction(word.clone()))?;
+                    self.next += 1;
+                    let argument = self.expr()?;
+                    self.expect(Token::RightParen)?;
+                    Ok(Expr::Call(function, Box::new(argument)))
+                }
+                Some(Token::Comparator(_)) | Some(Token::Match) => match Field::from_name(&word) {
+                    Some(field) => self.comparison(field),
+                    None => Err(QueryError::InvalidComparison(word)),
+                },
+                _ => Ok(match word.as_str() {
+                    "all" => Expr::All,
+                    "roots" => Expr::Roots,
+                    "leaves" => Expr::Leaves,
+                    "crashed" => Expr::Crashed,
+                    "seeds" => Expr::Seeds,
+                    _ => Expr::Node(word),
+                }),
+            },
+            _ => {
+                self.next -= 1;
+                Err(self.unexpected())
+            }
+        }
+    }
+
+    fn comparison(&mut self, field: Field) -> Result<Expr> {
+        let comparator = self.consume()?;
+        let value = match self.consume()? {
+            Token::Word(value) | Token::Quoted(value) => value,
+            _ => {
+                self.next -= 1;
+                return Err(self.unexpected());
+            }
+        };
+        let comparison = match comparator {
+            Token::Match if !field.is_numeric() => Comparison::Matches(Regex::new(&value)?),
+            Token::Comparator(comparator) if field.is_numeric() => match value.parse::<u64>() {
+                Ok(value) => Comparison::Number(comparator, value),
+                Err(_) => return Err(QueryError::InvalidComparison(value)),
+            },
+            Token::Comparator(comparator) => Comparison::String(comparator, value),
+            _ => return Err(QueryError::InvalidComparison(format!("{:?}", field))),
+        };
+        Ok(Expr::Compare(field, comparison))
+    }
+}
+
+pub(super) fn parse(query: &str) -> Result<Expr> {
+    let mut parser = Parser {
+        tokens: tokenize(query)?,
+        next: 0,
+    };
+    let expr = parser.expr()?;
+    if parser.next < parser.tokens.len() {
+        return Err(parser.unexpected());
+    }
+    Ok(expr)
+}
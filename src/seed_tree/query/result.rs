@@ -0,0 +1,3 @@
+use super::error::QueryError;
+
+pub type Result<T> = std::result::Result<T, QueryError>;
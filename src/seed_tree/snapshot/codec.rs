@@ -0,0 +1,116 @@
+use super::error::SnapshotError;
+use super::result::Result;
+use std::io::{Read, Write};
+use std::path::PathBuf;
+
+// NOTE: All integers are encoded in little endian
+pub(crate) struct Encoder<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> Encoder<W> {
+    pub(crate) fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    pub(crate) fn write_raw(&mut self, bytes: &[u8]) -> Result<()> {
+        Ok(self.writer.write_all(bytes)?)
+    }
+
+    pub(crate) fn write_bool(&mut self, value: bool) -> Result<()> {
+        self.write_raw(&[value as u8])
+    }
+
+    pub(crate) fn write_u32(&mut self, value: u32) -> Result<()> {
+        self.write_raw(&value.to_le_bytes())
+    }
+
+    pub(crate) fn write_u64(&mut self, value: u64) -> Result<()> {
+        self.write_raw(&value.to_le_bytes())
+    }
+
+    pub(crate) fn write_bytes(&mut self, bytes: &[u8]) -> Result<()> {
+        self.write_u64(bytes.len() as u64)?;
+        self.write_raw(bytes)
+    }
+
+    pub(crate) fn write_str(&mut self, value: &str) -> Result<()> {
+        self.write_bytes(value.as_bytes())
+    }
+
+    #[cfg(unix)]
+    pub(crate) fn write_path(&mut self, value: &std::path::Path) -> Result<()> {
+        use std::os::unix::ffi::OsStrExt;
+        self.write_bytes(value.as_os_str().as_bytes())
+    }
+
+    #[cfg(not(unix))]
+    pub(crate) fn write_path(&mut self, value: &std::path::Path) -> Result<()> {
+        self.write_str(value.to_str().ok_or(SnapshotError::StringEncoding)?)
+    }
+
+    pub(crate) fn flush(&mut self) -> Result<()> {
+        Ok(self.writer.flush()?)
+    }
+}
+
+pub(crate) struct Decoder<R: Read> {
+    reader: R,
+}
+
+impl<R: Read> Decoder<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self { reader }
+    }
+
+    pub(crate) fn read_raw(&mut self, buf: &mut [u8]) -> Result<()> {
+        Ok(self.reader.read_exact(buf)?)
+    }
+
+    pub(crate) fn read_bool(&mut self) -> Result<bool> {
+        let mut buf = [0u8; 1];
+        self.read_raw(&mut buf)?;
+        Ok(buf[0] != 0)
+    }
+
+    pub(crate) fn read_u32(&mut self) -> Result<u32> {
+        let mut buf = [0u8; 4];
+        self.read_raw(&mut buf)?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    pub(crate) fn read_u64(&mut self) -> Result<u64> {
+        let mut buf = [0u8; 8];
+        self.read_raw(&mut buf)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    pub(crate) fn read_bytes(&mut self) -> Result<Vec<u8>> {
+        let length = self.read_u64()?;
+        let mut buf = Vec::new();
+        // NOTE: Do not trust `length` to allocate buffer at once since snapshot may be broken
+        (&mut self.reader).take(length).read_to_end(&mut buf)?;
+        if (buf.len() as u64) < length {
+            return Err(SnapshotError::IoError(
+                std::io::ErrorKind::UnexpectedEof.into(),
+            ));
+        }
+        Ok(buf)
+    }
+
+    pub(crate) fn read_string(&mut self) -> Result<String> {
+        String::from_utf8(self.read_bytes()?).map_err(|_| SnapshotError::StringEncoding)
+    }
+
+    #[cfg(unix)]
+    pub(crate) fn read_path(&mut self) -> Result<PathBuf> {
+        use std::ffi::OsString;
+        use std::os::unix::ffi::OsStringExt;
+        Ok(PathBuf::from(OsString::from_vec(self.read_bytes()?)))
+    }
+
+    #[cfg(not(unix))]
+    pub(crate) fn read_path(&mut self) -> Result<PathBuf> {
+        Ok(PathBuf::from(self.read_string()?))
+    }
+}
@@ -0,0 +1,58 @@
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    IoError(io::Error),
+    InvalidMagic,
+    UnsupportedVersion(u32),
+    StringEncoding,
+    NodeIndexOutOfRange(u32),
+    JobIndexOutOfRange(u32),
+    SourceIndexOutOfRange(u32),
+    UnknownHashAlgorithm(String),
+    UnknownWeakEdgeReason(String),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IoError(error) => write!(f, "{}", error),
+            Self::InvalidMagic => write!(f, "not a snapshot file"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported snapshot version {}", version)
+            }
+            Self::StringEncoding => write!(f, "string in snapshot is not valid UTF-8"),
+            Self::NodeIndexOutOfRange(index) => {
+                write!(f, "node index {} in snapshot is out of range", index)
+            }
+            Self::JobIndexOutOfRange(index) => {
+                write!(f, "job index {} in snapshot is out of range", index)
+            }
+            Self::SourceIndexOutOfRange(index) => {
+                write!(f, "source index {} in snapshot is out of range", index)
+            }
+            Self::UnknownHashAlgorithm(name) => {
+                write!(f, "unknown hash algorithm {:?} in snapshot", name)
+            }
+            Self::UnknownWeakEdgeReason(name) => {
+                write!(f, "unknown reason of weak edge {:?} in snapshot", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::IoError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SnapshotError {
+    fn from(error: io::Error) -> Self {
+        Self::IoError(error)
+    }
+}
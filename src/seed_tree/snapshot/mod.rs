@@ -0,0 +1,436 @@
+pub(crate) mod codec;
+pub mod error;
+pub mod result;
+
+use self::codec::{Decoder, Encoder};
+use self::error::SnapshotError;
+use self::result::Result;
+use crate::seed_tree::directed_edge::DirectedEdge;
+use crate::seed_tree::discovery_stats::DiscoveryStats;
+use crate::seed_tree::file_hash::FileHash;
+use crate::seed_tree::hash_algorithm::HashAlgorithm;
+use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+use crate::seed_tree::mutation_graph_node::MutationGraphNode;
+use crate::seed_tree::node_id::{LabelId, NodeId};
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::weak_edge::WeakEdgeReason;
+use crate::seed_tree::MutationGraph;
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+// Snapshot file layout (all integers are little endian):
+//
+//   MAGIC VERSION
+//   <# of nodes: u64>      { name, crashed, file, hash, has discovered: bool, discovered: u64, initial seed: bool }*
+//   <# of edges: u64>      { parent index: u32, child index: u32, label }*
+//   <# of weak edges: u64> { parent index: u32, child index: u32, label, reason,
+//                            <# of path nodes: u64> { node index: u32 }* }*
+//   <# of jobs: u64>       { name }*
+//   <# of edge jobs: u64>  { parent index: u32, child index: u32, <# of jobs: u64> { job index: u32 }* }*
+//   <# of node stats: u64> { node index: u32, execs, cov, ft, cov gain, ft gain, corpus units, corpus bytes: u64 }*
+//   <# of sources: u64>    { name }*
+//   <# of node sources: u64> { node index: u32, <# of sources: u64> { source index: u32 }* }*
+//
+// Hash of node is name of its algorithm ("" if unknown) followed by raw digest.
+// Edges refer nodes by index of node table (i.e. `NodeId`) to keep snapshot compact.
+pub const MAGIC: &[u8; 8] = b"SEEDTREE";
+pub const VERSION: u32 = 1;
+
+pub fn write_snapshot<W: Write>(graph: &MutationGraph, writer: W) -> Result<()> {
+    let mut encoder = Encoder::new(writer);
+    encoder.write_raw(MAGIC)?;
+    encoder.write_u32(VERSION)?;
+
+    // NOTE: Nodes are written in order of their ids, so edges can refer nodes by id as is
+    encoder.write_u64(graph.node.len() as u64)?;
+    for node in graph.node.iter() {
+        encoder.write_str(&node.name)?;
+        encoder.write_bool(node.crashed)?;
+        encoder.write_path(&node.file)?;
+        write_file_hash(&mut encoder, &node.hash)?;
+        encoder.write_bool(node.discovered.is_some())?;
+        encoder.write_u64(node.discovered.unwrap_or_default())?;
+        encoder.write_bool(node.initial_seed)?;
+    }
+
+    let edges: Vec<(NodeId, NodeId, LabelId)> = graph
+        .parent
+        .iter()
+        .enumerate()
+        .filter_map(|(child, parent)| {
+            parent.map(|(parent, label)| (parent, NodeId::from(child), label))
+        })
+        .collect();
+    encoder.write_u64(edges.len() as u64)?;
+    for (parent, child, label) in edges.iter() {
+        encoder.write_u32(parent.index() as u32)?;
+        encoder.write_u32(child.index() as u32)?;
+        encoder.write_str(&graph.label[label.index()])?;
+    }
+    let mut weak_edges: Vec<_> = graph.weak_edge.iter().collect();
+    // NOTE: Sort weak edges to make snapshots of same graph identical
+    weak_edges.sort();
+    encoder.write_u64(weak_edges.len() as u64)?;
+    for ((parent, child), (label, reason, path)) in weak_edges {
+        encoder.write_u32(parent.index() as u32)?;
+        encoder.write_u32(child.index() as u32)?;
+        encoder.write_str(&graph.label[label.index()])?;
+        encoder.write_str(reason.name())?;
+        encoder.write_u64(path.len() as u64)?;
+        for id in path.iter() {
+            encoder.write_u32(id.index() as u32)?;
+        }
+    }
+
+    encoder.write_u64(graph.job.len() as u64)?;
+    for job in graph.job.iter() {
+        encoder.write_str(job)?;
+    }
+    let mut edge_jobs: Vec<_> = graph.edge_job.iter().collect();
+    edge_jobs.sort();
+    encoder.write_u64(edge_jobs.len() as u64)?;
+    for ((parent, child), jobs) in edge_jobs {
+        encoder.write_u32(parent.index() as u32)?;
+        encoder.write_u32(child.index() as u32)?;
+        encoder.write_u64(jobs.len() as u64)?;
+        for job in jobs.iter() {
+            encoder.write_u32(job.index() as u32)?;
+        }
+    }
+
+    let node_stats: Vec<_> = graph
+        .node
+        .iter()
+        .enumerate()
+        .filter_map(|(id, node)| node.stats.map(|stats| (id, stats)))
+        .collect();
+    encoder.write_u64(node_stats.len() as u64)?;
+    for (id, stats) in node_stats.iter() {
+        encoder.write_u32(*id as u32)?;
+        for value in [
+            stats.execs,
+            stats.cov,
+            stats.ft,
+            stats.cov_gain,
+            stats.ft_gain,
+            stats.corpus_units,
+            stats.corpus_bytes,
+        ]
+        .iter()
+        {
+            encoder.write_u64(*value)?;
+        }
+    }
+
+    encoder.write_u64(graph.source.len() as u64)?;
+    for source in graph.source.iter() {
+        encoder.write_str(source)?;
+    }
+    let mut node_sources: Vec<_> = graph.node_source.iter().collect();
+    node_sources.sort();
+    encoder.write_u64(node_sources.len() as u64)?;
+    for (node, sources) in node_sources {
+        encoder.write_u32(node.index() as u32)?;
+        encoder.write_u64(sources.len() as u64)?;
+        for source in sources.iter() {
+            encoder.write_u32(source.index() as u32)?;
+        }
+    }
+
+    encoder.flush()
+}
+
+fn write_file_hash<W: Write>(encoder: &mut Encoder<W>, hash: &FileHash) -> Result<()> {
+    encoder.write_str(hash.algorithm().map_or("", |v| v.name()))?;
+    encoder.write_raw(hash.as_bytes())
+}
+
+fn read_file_hash<R: Read>(decoder: &mut Decoder<R>) -> Result<FileHash> {
+    let name = decoder.read_string()?;
+    if name.is_empty() {
+        return Ok(FileHash::default());
+    }
+    let algorithm = match HashAlgorithm::from_name(&name) {
+        Some(v) => v,
+        None => return Err(SnapshotError::UnknownHashAlgorithm(name)),
+    };
+    let mut digest = vec![0u8; algorithm.digest_len()];
+    decoder.read_raw(&mut digest)?;
+    Ok(FileHash::from_bytes(algorithm, &digest).expect("Digest of algorithm"))
+}
+
+pub fn read_snapshot<R: Read>(reader: R) -> Result<MutationGraph> {
+    let mut decoder = Decoder::new(reader);
+
+    let mut magic = [0u8; 8];
+    decoder.read_raw(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(SnapshotError::InvalidMagic);
+    }
+    let version = decoder.read_u32()?;
+    if version != VERSION {
+        return Err(SnapshotError::UnsupportedVersion(version));
+    }
+
+    let mut graph = MutationGraph::new();
+
+    let mut names: Vec<NodeName> = Vec::new();
+    for _ in 0..decoder.read_u64()? {
+        let mut node = MutationGraphNode::new_with_metadata(
+            &decoder.read_string()?,
+            decoder.read_bool()?,
+            &decoder.read_path()?,
+            &read_file_hash(&mut decoder)?,
+        );
+        let has_discovered = decoder.read_bool()?;
+        let discovered = decoder.read_u64()?;
+        node.discovered = if has_discovered {
+            Some(discovered)
+        } else {
+            None
+        };
+        node.initial_seed = decoder.read_bool()?;
+        graph.add_node(&node);
+        names.push(node.name);
+    }
+
+    for _ in 0..decoder.read_u64()? {
+        let lookup = |index: u32| match names.get(index as usize) {
+            Some(name) => Ok(name.clone()),
+            None => Err(SnapshotError::NodeIndexOutOfRange(index)),
+        };
+        graph.add_edge(&MutationGraphEdge {
+            parent: lookup(decoder.read_u32()?)?,
+            child: lookup(decoder.read_u32()?)?,
+            label: decoder.read_string()?,
+        });
+    }
+    let id_of = |index: u32| match names.get(index as usize) {
+        Some(_) => Ok(NodeId::from(index as usize)),
+        None => Err(SnapshotError::NodeIndexOutOfRange(index)),
+    };
+    // NOTE: Reasons and paths of weak edges are restored as recorded rather than recovered from tree edges,
+    //       since tree edges they conflicted with may be re-parented later
+    for _ in 0..decoder.read_u64()? {
+        let parent = id_of(decoder.read_u32()?)?;
+        let child = id_of(decoder.read_u32()?)?;
+        let label = graph.intern_label(&decoder.read_string()?);
+        let name = decoder.read_string()?;
+        let reason = match WeakEdgeReason::from_name(&name) {
+            Some(v) => v,
+            None => return Err(SnapshotError::UnknownWeakEdgeReason(name)),
+        };
+        let mut path = Vec::new();
+        for _ in 0..decoder.read_u64()? {
+            path.push(id_of(decoder.read_u32()?)?);
+        }
+        graph
+            .weak_edge
+            .insert((parent, child), (label, reason, path));
+    }
+
+    let mut jobs: Vec<String> = Vec::new();
+    for _ in 0..decoder.read_u64()? {
+        jobs.push(decoder.read_string()?);
+    }
+    for job in jobs.iter() {
+        graph.intern_job(job);
+    }
+    for _ in 0..decoder.read_u64()? {
+        let lookup = |index: u32| match names.get(index as usize) {
+            Some(name) => Ok(name.clone()),
+            None => Err(SnapshotError::NodeIndexOutOfRange(index)),
+        };
+        let edge = DirectedEdge::new(&lookup(decoder.read_u32()?)?, &lookup(decoder.read_u32()?)?);
+        for _ in 0..decoder.read_u64()? {
+            let index = decoder.read_u32()?;
+            match jobs.get(index as usize) {
+                Some(job) => graph.record_job_of(&edge, job),
+                None => return Err(SnapshotError::JobIndexOutOfRange(index)),
+            }
+        }
+    }
+
+    for _ in 0..decoder.read_u64()? {
+        let index = decoder.read_u32()?;
+        let stats = DiscoveryStats {
+            execs: decoder.read_u64()?,
+            cov: decoder.read_u64()?,
+            ft: decoder.read_u64()?,
+            cov_gain: decoder.read_u64()?,
+            ft_gain: decoder.read_u64()?,
+            corpus_units: decoder.read_u64()?,
+            corpus_bytes: decoder.read_u64()?,
+        };
+        match graph.node.get_mut(index as usize) {
+            Some(node) => node.stats = Some(stats),
+            None => return Err(SnapshotError::NodeIndexOutOfRange(index)),
+        }
+    }
+
+    let mut sources: Vec<String> = Vec::new();
+    for _ in 0..decoder.read_u64()? {
+        sources.push(decoder.read_string()?);
+    }
+    for source in sources.iter() {
+        graph.intern_source(source);
+    }
+    for _ in 0..decoder.read_u64()? {
+        let index = decoder.read_u32()?;
+        if index as usize >= names.len() {
+            return Err(SnapshotError::NodeIndexOutOfRange(index));
+        }
+        for _ in 0..decoder.read_u64()? {
+            let source = decoder.read_u32()?;
+            match sources.get(source as usize) {
+                Some(source) => graph.record_source_of(NodeId::from(index as usize), source),
+                None => return Err(SnapshotError::SourceIndexOutOfRange(source)),
+            }
+        }
+    }
+
+    Ok(graph)
+}
+
+pub fn save_snapshot<P: AsRef<Path>>(graph: &MutationGraph, path: P) -> Result<()> {
+    write_snapshot(graph, BufWriter::new(File::create(path)?))
+}
+
+pub fn load_snapshot<P: AsRef<Path>>(path: P) -> Result<MutationGraph> {
+    read_snapshot(BufReader::new(File::open(path)?))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::directed_edge::DirectedEdge;
+    use crate::seed_tree::discovery_stats::DiscoveryStats;
+    use crate::seed_tree::file_hash::FileHash;
+    use crate::seed_tree::hash_algorithm::HashAlgorithm;
+    use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+    use crate::seed_tree::mutation_graph_node::MutationGraphNode;
+    use crate::seed_tree::node_name::NodeName;
+    use crate::seed_tree::snapshot::error::SnapshotError;
+    use crate::seed_tree::snapshot::{read_snapshot, write_snapshot, MAGIC, VERSION};
+    use crate::seed_tree::MutationGraph;
+    use std::collections::HashSet;
+    use std::path::Path;
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let node_1 = NodeName::from("node_1");
+        let node_2 = NodeName::from("node_2");
+        let node_3 = NodeName::from("node_3");
+
+        let mut graph = MutationGraph::new();
+        graph.add_node(&MutationGraphNode {
+            initial_seed: true,
+            ..MutationGraphNode::new_with_metadata(
+                &node_1,
+                false,
+                Path::new("in/seed"),
+                &FileHash::of(HashAlgorithm::Sha1, b""),
+            )
+        });
+        graph.add_node(&MutationGraphNode {
+            stats: Some(DiscoveryStats {
+                execs: 1234,
+                cov: 812,
+                ft: 2011,
+                cov_gain: 2,
+                ft_gain: 5,
+                corpus_units: 45,
+                corpus_bytes: 3072,
+            }),
+            discovered: Some(8024),
+            ..MutationGraphNode::new_with_metadata(
+                &node_3,
+                true,
+                Path::new("crashes/id:000000,sig:06,src:000001,op:havoc,rep:2"),
+                &FileHash::of(HashAlgorithm::Blake3, b"crash"),
+            )
+        });
+        graph.add_edge(&MutationGraphEdge {
+            parent: node_1.clone(),
+            child: node_2.clone(),
+            label: String::from("havoc"),
+        });
+        graph.add_edge_of_job(
+            &MutationGraphEdge {
+                parent: node_2.clone(),
+                child: node_3.clone(),
+                label: String::from("splice"),
+            },
+            "fork/1",
+        );
+        graph.record_source_of(graph.id_of(&node_1).unwrap(), "libfuzzer");
+        graph.record_source_of(graph.id_of(&node_1).unwrap(), "afl");
+        graph.record_source_of(graph.id_of(&node_3).unwrap(), "afl");
+        graph.add_edge(&MutationGraphEdge {
+            parent: node_3.clone(),
+            child: node_1.clone(),
+            label: String::from("Closed chain"),
+        });
+        // Node 5 is re-parented from node 4 to node 6
+        let node_4 = NodeName::from("node_4");
+        let node_5 = NodeName::from("node_5");
+        let node_6 = NodeName::from("node_6");
+        graph.add_edge(&MutationGraphEdge::new(&node_4, &node_5));
+        graph.add_edge(&MutationGraphEdge::new(&node_6, &node_5));
+        // Node 2 in the closed chain above is re-parented from node 1 to node 7
+        graph.add_edge(&MutationGraphEdge::new(&NodeName::from("node_7"), &node_2));
+
+        let mut buf = Vec::new();
+        assert!(write_snapshot(&graph, &mut buf).is_ok());
+        let restored = read_snapshot(buf.as_slice()).unwrap();
+
+        for node in graph.nodes() {
+            let restored_node = restored.get_node(&node.name).unwrap();
+            assert_eq!(restored_node.crashed, node.crashed);
+            assert_eq!(restored_node.file, node.file);
+            assert_eq!(restored_node.hash, node.hash);
+            assert_eq!(restored_node.discovered, node.discovered);
+            assert_eq!(restored_node.stats, node.stats);
+            assert_eq!(restored_node.initial_seed, node.initial_seed);
+        }
+        assert_eq!(
+            restored.edges().collect::<HashSet<MutationGraphEdge>>(),
+            graph.edges().collect::<HashSet<MutationGraphEdge>>()
+        );
+        assert_eq!(restored.weak_edge.len(), 3);
+        assert_eq!(restored.weak_edges(), graph.weak_edges());
+        assert_eq!(
+            restored.jobs_of(&DirectedEdge::new(&node_2, &node_3)),
+            vec!["fork/1"]
+        );
+        assert_eq!(restored.sources(), ["libfuzzer", "afl"]);
+        assert_eq!(restored.sources_of(&node_1), vec!["libfuzzer", "afl"]);
+        assert_eq!(restored.sources_of(&node_3), vec!["afl"]);
+        assert!(restored.sources_of(&node_2).is_empty());
+        assert_eq!(restored.roots(), graph.roots());
+        assert_eq!(
+            restored.lookup_by_file_hash(&FileHash::of(HashAlgorithm::Blake3, b"crash")),
+            Ok(&node_3)
+        );
+    }
+
+    #[test]
+    fn test_snapshot_invalid_magic() {
+        match read_snapshot(&b"digraph {\n}\n"[..]) {
+            Err(SnapshotError::InvalidMagic) => (),
+            other => panic!("Unexpected result: {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_snapshot_unsupported_version() {
+        let mut buf = Vec::new();
+        assert!(write_snapshot(&MutationGraph::new(), &mut buf).is_ok());
+        buf[MAGIC.len()..MAGIC.len() + 4].copy_from_slice(&(VERSION + 1).to_le_bytes());
+        match read_snapshot(buf.as_slice()) {
+            Err(SnapshotError::UnsupportedVersion(version)) => assert_eq!(version, VERSION + 1),
+            other => panic!("Unexpected result: {:?}", other.map(|_| ())),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+use super::error::SnapshotError;
+
+pub type Result<T> = std::result::Result<T, SnapshotError>;
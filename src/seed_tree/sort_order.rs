@@ -0,0 +1,27 @@
+/// Order of nodes in listings and DOT graphs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    /// Lexicographic order of node names
+    #[default]
+    Name,
+    /// Order of node declaration in input (i.e. `NodeId`)
+    Id,
+    /// Order of discovery time. Nodes without discovery time precede others in order of id
+    Discovery,
+    /// Parents precede their children. Roots and siblings are ordered by name
+    Topological,
+}
+
+impl SortOrder {
+    pub const NAMES: &'static [&'static str] = &["name", "id", "discovery", "topo"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "name" => Some(Self::Name),
+            "id" => Some(Self::Id),
+            "discovery" => Some(Self::Discovery),
+            "topo" => Some(Self::Topological),
+            _ => None,
+        }
+    }
+}
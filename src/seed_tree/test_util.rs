@@ -0,0 +1,37 @@
+use crate::seed_tree::file_hash::FileHash;
+use crate::seed_tree::hash_algorithm::HashAlgorithm;
+use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+use crate::seed_tree::mutation_graph_node::MutationGraphNode;
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::MutationGraph;
+use std::path::Path;
+
+/// Fake hash of contents `data`, which is unknown if empty
+pub(crate) fn hash_of(data: &str) -> FileHash {
+    if data.is_empty() {
+        FileHash::default()
+    } else {
+        FileHash::of(HashAlgorithm::Sha1, data.as_bytes())
+    }
+}
+
+/// Builds graph from (name, contents, crashed) of nodes and (parent, child) of edges labeled `havoc`
+pub(crate) fn graph_of(nodes: &[(&str, &str, bool)], edges: &[(&str, &str)]) -> MutationGraph {
+    let mut graph = MutationGraph::new();
+    for (name, data, crashed) in nodes.iter() {
+        graph.add_node(&MutationGraphNode::new_with_metadata(
+            &NodeName::from(*name),
+            *crashed,
+            Path::new(""),
+            &hash_of(data),
+        ));
+    }
+    for (parent, child) in edges.iter() {
+        graph.add_edge(&MutationGraphEdge {
+            parent: NodeName::from(*parent),
+            child: NodeName::from(*child),
+            label: String::from("havoc"),
+        });
+    }
+    graph
+}
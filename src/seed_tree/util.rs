@@ -1,13 +1,14 @@
-use super::file_hash::FileHash;
+use super::file_hash::{FileHash, FileHasher};
+use super::hash_algorithm::HashAlgorithm;
 
 #[allow(unused_imports)]
 use log::trace;
-use sha1::{Digest, Sha1};
 use std::fs;
 use std::io;
-use std::io::BufReader;
-use std::io::Read;
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
 
 pub fn assert_path_exists<P: AsRef<Path>>(path: P) -> P {
     assert!(
@@ -18,27 +19,93 @@ pub fn assert_path_exists<P: AsRef<Path>>(path: P) -> P {
     path
 }
 
-pub fn calc_file_hash<P: AsRef<Path>>(path: P) -> Result<FileHash, io::Error> {
+pub fn calc_file_hash<P: AsRef<Path>>(
+    path: P,
+    algorithm: HashAlgorithm,
+) -> Result<FileHash, io::Error> {
     // trace!("calc_file_hash: path={:?}", path.as_ref());
-    let mut file = BufReader::new(fs::File::open(path)?);
-    let mut buf = Vec::new();
-    file.read_to_end(&mut buf)?;
-    let hash = Sha1::digest(buf);
-    Ok(base16ct::lower::encode_string(&hash))
+    let mut file = fs::File::open(path)?;
+    // NOTE: Stream file contents to hasher not to load large files on memory
+    let mut hasher = FileHasher::new(algorithm);
+    io::copy(&mut file, &mut hasher)?;
+    Ok(hasher.finalize())
+}
+
+pub fn available_jobs() -> usize {
+    thread::available_parallelism()
+        .map(|v| v.get())
+        .unwrap_or(1)
+}
+
+/// Applies `f` to each of `items` on at most `jobs` worker threads. Order of results is same as `items`.
+pub fn parallel_map<T, R, F>(items: &[T], jobs: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let jobs = jobs.max(1).min(items.len());
+    if jobs <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(items.iter().map(|_| None).collect());
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            scope.spawn(|| {
+                let mut done = Vec::new();
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    if i >= items.len() {
+                        break;
+                    }
+                    done.push((i, f(&items[i])));
+                }
+                let mut results = results.lock().unwrap();
+                for (i, result) in done {
+                    results[i] = Some(result);
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|v| v.expect("Every item is processed by workers"))
+        .collect()
 }
 
 #[cfg(test)]
 mod test {
-    use super::calc_file_hash;
+    use super::{calc_file_hash, parallel_map};
+    use crate::seed_tree::hash_algorithm::HashAlgorithm;
     use crate::seed_tree::FileHash;
 
     #[test]
     fn test_calc_file_hash() {
-        let result = calc_file_hash("test/sample/seeds/fuzzer-test-suite-openssl-1.0.1f/0dafd00a785bd3d2cb36722c29f0dd23497833b0");
+        let result = calc_file_hash("test/sample/seeds/fuzzer-test-suite-openssl-1.0.1f/0dafd00a785bd3d2cb36722c29f0dd23497833b0", HashAlgorithm::Sha1);
         assert!(result.is_ok(), "result={:?}", result);
         assert_eq!(
             result.unwrap(),
-            FileHash::from("0dafd00a785bd3d2cb36722c29f0dd23497833b0")
+            FileHash::from_hex(
+                HashAlgorithm::Sha1,
+                "0dafd00a785bd3d2cb36722c29f0dd23497833b0"
+            )
+            .unwrap()
         );
     }
+
+    #[test]
+    fn test_parallel_map() {
+        let items: Vec<usize> = (0..1000).collect();
+        for jobs in [0, 1, 4, 2000].iter() {
+            assert_eq!(
+                parallel_map(&items, *jobs, |v| v * 2),
+                items.iter().map(|v| v * 2).collect::<Vec<usize>>()
+            );
+        }
+    }
 }
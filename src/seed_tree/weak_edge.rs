@@ -0,0 +1,57 @@
+use super::mutation_graph_edge::MutationGraphEdge;
+use super::node_name::NodeName;
+use std::fmt;
+
+/// Why an edge is stored as weak edge instead of a tree edge
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WeakEdgeReason {
+    // Edge from a node to itself
+    SelfLoop,
+    // Same edge already exists as tree edge
+    Duplicate,
+    // Child is an ancestor of parent, so the edge closes a cycle
+    ClosedChain,
+    // Child already has another parent
+    Reparent,
+    // Declared as weak edge by input (e.g. dashed edge in DOT) without conflicts
+    Declared,
+}
+
+impl WeakEdgeReason {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "self-loop" => Some(Self::SelfLoop),
+            "duplicate" => Some(Self::Duplicate),
+            "closed-chain" => Some(Self::ClosedChain),
+            "re-parent" => Some(Self::Reparent),
+            "declared" => Some(Self::Declared),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SelfLoop => "self-loop",
+            Self::Duplicate => "duplicate",
+            Self::ClosedChain => "closed-chain",
+            Self::Reparent => "re-parent",
+            Self::Declared => "declared",
+        }
+    }
+}
+
+impl fmt::Display for WeakEdgeReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeakEdge {
+    pub edge: MutationGraphEdge,
+    pub reason: WeakEdgeReason,
+    /// Path of tree edges conflicting with this edge:
+    /// `[child, ..., parent]` for closed chain, `[existing parent, child]` for re-parent and duplicate,
+    /// `[node]` for self-loop and empty for declared weak edge.
+    pub path: Vec<NodeName>,
+}
@@ -0,0 +1,239 @@
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::result::Result;
+use clap::ArgMatches;
+use std::fs;
+use std::path::Path;
+
+/// Directories of crashing and non-crashing inputs which Aurora's tracing stage reads
+pub(crate) const AURORA_CRASHES_DIR: &str = "crashes";
+pub(crate) const AURORA_NON_CRASHES_DIR: &str = "non_crashes";
+
+/// Kinds of nodes in crash exploration of Aurora, told apart by names given with `--aurora`
+/// (see `AFLExtensions`). Only crash and non-crash inputs are Aurora's sets of inputs to trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NodeKind {
+    /// Input in `crashes/`, named `crash-<id>`
+    Crash,
+    /// Input in `non_crashes/`, named `nc-<id>`
+    NonCrash,
+    /// Input in `queue/`, which crashes too
+    Queue,
+    /// Initial seed (i.e. root), which crashes too
+    Seed,
+}
+
+impl NodeKind {
+    fn of(graph: &MutationGraph, name: &NodeName) -> Self {
+        if graph.parent_of(name).is_none() {
+            NodeKind::Seed
+        } else if name.starts_with("crash-") {
+            NodeKind::Crash
+        } else if name.starts_with("nc-") {
+            NodeKind::NonCrash
+        } else {
+            NodeKind::Queue
+        }
+    }
+
+    fn crashes(self) -> bool {
+        self != NodeKind::NonCrash
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            NodeKind::Crash => "crash",
+            NodeKind::NonCrash => "non-crash",
+            NodeKind::Queue => "queue",
+            NodeKind::Seed => "seed",
+        }
+    }
+}
+
+/// Summarizes crash exploration of Aurora (see `--aurora`).
+/// With ID, counts crashing and non-crashing siblings of each node in lineage of ID.
+/// With `--export`, copies crash and non-crash inputs to directories in the layout of Aurora's tracing stage.
+#[allow(unused)]
+pub(crate) fn aurora(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
+    if let Some(export_dir) = matches.value_of("EXPORT_DIR") {
+        export(graph, Path::new(export_dir))?;
+    }
+
+    match matches.value_of("ID") {
+        Some(id) => lineage(graph, &NodeName::from(id)),
+        None => {
+            let mut counts = [0usize; 4];
+            for node in graph.nodes() {
+                counts[NodeKind::of(graph, &node.name) as usize] += 1;
+            }
+            println!("crash inputs:     {}", counts[NodeKind::Crash as usize]);
+            println!("non-crash inputs: {}", counts[NodeKind::NonCrash as usize]);
+            println!("queue entries:    {}", counts[NodeKind::Queue as usize]);
+            println!("seeds:            {}", counts[NodeKind::Seed as usize]);
+            Ok(())
+        }
+    }
+}
+
+// Counts crashing and non-crashing siblings of node `name`
+fn siblings_of(graph: &MutationGraph, name: &NodeName) -> (usize, usize) {
+    let siblings: Vec<&NodeName> = match graph.parent_of(name) {
+        Some(parent) => graph
+            .children_of(parent)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|sibling| *sibling != name)
+            .collect(),
+        None => Vec::new(),
+    };
+    let crashes = siblings
+        .iter()
+        .filter(|sibling| NodeKind::of(graph, sibling).crashes())
+        .count();
+    (crashes, siblings.len() - crashes)
+}
+
+fn lineage(graph: &MutationGraph, id: &NodeName) -> Result<()> {
+    println!(
+        "{:<40} {:<9} {:>15} {:>19}",
+        "node", "kind", "crash siblings", "non-crash siblings"
+    );
+    let (mut crash_siblings, mut non_crash_siblings) = (0, 0);
+    for name in graph.self_and_its_predecessors_of(id)? {
+        let (crashes, non_crashes) = siblings_of(graph, name);
+        println!(
+            "{:<40} {:<9} {:>15} {:>19}",
+            name,
+            NodeKind::of(graph, name).name(),
+            crashes,
+            non_crashes
+        );
+        crash_siblings += crashes;
+        non_crash_siblings += non_crashes;
+    }
+    eprintln!(
+        "Lineage has {} crashing and {} non-crashing siblings",
+        crash_siblings, non_crash_siblings
+    );
+    Ok(())
+}
+
+// NOTE: Queue entries and initial seeds are skipped, since Aurora traces only inputs of its crash and non-crash sets
+fn export(graph: &MutationGraph, export_dir: &Path) -> Result<()> {
+    for directory in [AURORA_CRASHES_DIR, AURORA_NON_CRASHES_DIR].iter() {
+        let directory = export_dir.join(directory);
+        fs::create_dir_all(&directory)
+            .map_err(|why| SubcommandError::from(why).in_file(&directory))?;
+    }
+
+    let (mut exported, mut skipped) = (0, 0);
+    // NOTE: Inputs are named by node names, which are unique unlike file names across directories
+    for node in graph.nodes() {
+        let directory = match NodeKind::of(graph, &node.name) {
+            NodeKind::Crash => AURORA_CRASHES_DIR,
+            NodeKind::NonCrash => AURORA_NON_CRASHES_DIR,
+            NodeKind::Queue | NodeKind::Seed => continue,
+        };
+        if node.file.as_os_str().is_empty() {
+            skipped += 1;
+            continue;
+        }
+        fs::copy(&node.file, export_dir.join(directory).join(&node.name))
+            .map_err(|why| SubcommandError::from(why).in_file(&node.file))?;
+        exported += 1;
+    }
+    log::info!(
+        "Exported {} inputs to {:?} ({} inputs without files are skipped)",
+        exported,
+        export_dir,
+        skipped
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{export, siblings_of, NodeKind, AURORA_CRASHES_DIR, AURORA_NON_CRASHES_DIR};
+    use crate::seed_tree::node_name::NodeName;
+    use crate::seed_tree::parser::afl::{parse_afl_input_directories, AFLExtensions};
+    use crate::seed_tree::MutationGraph;
+    use std::collections::HashSet;
+    use std::iter::FromIterator;
+
+    fn aurora_graph() -> MutationGraph {
+        parse_afl_input_directories(
+            HashSet::from_iter(["test/sample/seed-tree/afl-aurora-crash-exploration/"]),
+            &AFLExtensions {
+                aurora: true,
+                crash_inputs_dir: None,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_lineage_siblings() {
+        let graph = aurora_graph();
+        let seed = NodeName::from("crash-40fc056ab481fe4adb78715ea20a0fa486c81ec9");
+        let kinds: Vec<NodeKind> = [seed.as_str(), "000000", "crash-000008", "nc-143"]
+            .iter()
+            .map(|name| NodeKind::of(&graph, &NodeName::from(*name)))
+            .collect();
+        // Initial seed named like crash input is still a seed
+        assert_eq!(
+            kinds,
+            vec![
+                NodeKind::Seed,
+                NodeKind::Queue,
+                NodeKind::Crash,
+                NodeKind::NonCrash
+            ]
+        );
+
+        let siblings: Vec<(usize, usize)> = graph
+            .self_and_its_predecessors_of(&NodeName::from("crash-000008"))
+            .unwrap()
+            .into_iter()
+            .map(|name| siblings_of(&graph, name))
+            .collect();
+        assert_eq!(
+            siblings,
+            vec![(0, 0), (0, 0), (3, 4), (6, 0), (3, 0), (2, 0)]
+        );
+        assert_eq!(siblings_of(&graph, &NodeName::from("nc-143")), (4, 3));
+    }
+
+    #[test]
+    fn test_export() {
+        let graph = aurora_graph();
+        let export_dir = std::env::temp_dir().join(format!(
+            "seed-tree-analyzer-test-aurora-export-{}",
+            std::process::id()
+        ));
+        let exported = export(&graph, &export_dir);
+        let names_in = |directory: &str| -> Vec<String> {
+            let mut names: Vec<String> = std::fs::read_dir(export_dir.join(directory))
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+                .collect();
+            names.sort();
+            names
+        };
+        let (crashes, non_crashes) = (
+            names_in(AURORA_CRASHES_DIR),
+            names_in(AURORA_NON_CRASHES_DIR),
+        );
+        let _ = std::fs::remove_dir_all(&export_dir);
+
+        assert!(exported.is_ok());
+        // Queue entries and initial seeds are not in Aurora's sets of inputs
+        assert_eq!(
+            crashes,
+            (0..=8)
+                .map(|id| format!("crash-{:06}", id))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(non_crashes, vec!["nc-143", "nc-228", "nc-298", "nc-348"]);
+    }
+}
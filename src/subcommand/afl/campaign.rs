@@ -0,0 +1,164 @@
+use crate::seed_tree::mutation_graph_node::MutationGraphNode;
+use crate::seed_tree::parser::afl_stats::{find_afl_instances, AFLInstance};
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::result::Result;
+use clap::ArgMatches;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Summarizes AFL campaign from `fuzzer_stats` and `plot_data` of instances around OUTPUT_DIR(s) or input directories,
+/// correlating them with discovery times of nodes
+#[allow(unused)]
+pub(crate) fn campaign(
+    matches: &ArgMatches,
+    graph: &MutationGraph,
+    input_dirs: &[&Path],
+) -> Result<()> {
+    let min_plateau = match matches.value_of("PLATEAU") {
+        Some(seconds) => seconds
+            .parse::<u64>()
+            .map_err(|_| SubcommandError::InvalidArgument("PLATEAU", seconds.to_string()))?,
+        None => 600,
+    };
+    let directories: Vec<&Path> = match matches.values_of("OUTPUT_DIR") {
+        Some(directories) => directories.map(Path::new).collect(),
+        None => input_dirs.to_vec(),
+    };
+    let instances = find_afl_instances(&directories)?;
+    if instances.is_empty() {
+        return Err(SubcommandError::InvalidArgument(
+            "OUTPUT_DIR",
+            format!("no fuzzer_stats nor plot_data around {:?}", directories),
+        ));
+    }
+
+    // NOTE: Nodes belong to instance whose directory contains their files
+    let instance_of = |node: &MutationGraphNode| {
+        instances
+            .iter()
+            .position(|instance| node.file.starts_with(&instance.directory))
+    };
+    let plateaus: Vec<Vec<(u64, u64)>> = instances
+        .iter()
+        .map(|instance| match instance.plot_data.as_ref() {
+            Some(plot_data) => plot_data.plateaus(min_plateau),
+            None => Vec::new(),
+        })
+        .collect();
+
+    for (index, instance) in instances.iter().enumerate() {
+        let nodes = graph
+            .nodes()
+            .filter(|node| instance_of(node) == Some(index))
+            .count();
+        print_instance(instance, nodes, &plateaus[index]);
+    }
+
+    // Executions spent to find each node from its parent, grouped by depth
+    let mut costs: BTreeMap<usize, Vec<u64>> = BTreeMap::new();
+    let mut in_plateaus = Vec::new();
+    for node in graph.nodes() {
+        let (index, discovered) = match (instance_of(node), node.discovered) {
+            (Some(index), Some(discovered)) => (index, discovered / 1000),
+            _ => continue,
+        };
+        let instance = &instances[index];
+        let plot_data = match instance.plot_data.as_ref() {
+            Some(plot_data) => plot_data,
+            None => continue,
+        };
+        let parent_discovered = graph
+            .parent_of(&node.name)
+            .and_then(|parent| graph.get_node(parent))
+            .and_then(|parent| parent.discovered);
+        if let (Some(parent_discovered), Some(execs)) =
+            (parent_discovered, plot_data.execs_at(discovered))
+        {
+            let parent_execs = plot_data.execs_at(parent_discovered / 1000).unwrap_or(0);
+            costs
+                .entry(graph.rank_of(&node.name)?)
+                .or_default()
+                .push(execs.saturating_sub(parent_execs));
+        }
+        if let Some((start, end)) = plateaus[index]
+            .iter()
+            .find(|(start, end)| *start < discovered && discovered < *end)
+        {
+            in_plateaus.push((node, instance, discovered, *start, *end));
+        }
+    }
+
+    println!("execs per discovered path by depth:");
+    println!("  {:>5} {:>6} {:>12}", "depth", "paths", "execs/path");
+    for (depth, costs) in costs.iter() {
+        println!(
+            "  {:>5} {:>6} {:>12}",
+            depth,
+            costs.len(),
+            costs.iter().sum::<u64>() / costs.len() as u64
+        );
+    }
+
+    println!(
+        "nodes discovered during coverage plateaus (>= {} s): {}",
+        min_plateau,
+        in_plateaus.len()
+    );
+    in_plateaus.sort_by_key(|(node, instance, discovered, _, _)| {
+        (instance.directory.clone(), *discovered, node.name.clone())
+    });
+    for (node, instance, discovered, start, end) in in_plateaus.iter() {
+        println!(
+            "  {}\t{}\t{} s (plateau {}-{} s)",
+            node.name,
+            instance.name(),
+            discovered,
+            start,
+            end
+        );
+    }
+    Ok(())
+}
+
+fn print_instance(instance: &AFLInstance, nodes: usize, plateaus: &[(u64, u64)]) {
+    let stat = |key: &str| match instance.stats.as_ref().and_then(|v| v.get(key)) {
+        Some(value) => value.to_string(),
+        None => "-".to_string(),
+    };
+    let run_time = match instance.stats.as_ref().and_then(|v| v.run_time()) {
+        Some(run_time) => run_time.to_string(),
+        None => "-".to_string(),
+    };
+
+    println!("[{}] {}", instance.name(), instance.directory.display());
+    println!("  run time:      {} s", run_time);
+    println!(
+        "  execs:         {} ({}/s)",
+        stat("execs_done"),
+        stat("execs_per_sec")
+    );
+    println!(
+        "  corpus:        {} ({} nodes in seed tree)",
+        stat("corpus_count"),
+        nodes
+    );
+    println!(
+        "  crashes/hangs: {} / {}",
+        stat("saved_crashes"),
+        stat("saved_hangs")
+    );
+    println!("  max depth:     {}", stat("max_depth"));
+    println!(
+        "  coverage:      {} ({} edges)",
+        stat("bitmap_cvg"),
+        stat("edges_found")
+    );
+    if instance.plot_data.is_some() {
+        println!(
+            "  plateaus:      {} ({} s in total)",
+            plateaus.len(),
+            plateaus.iter().map(|(start, end)| end - start).sum::<u64>()
+        );
+    }
+}
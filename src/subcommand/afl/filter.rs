@@ -1,11 +1,13 @@
-use crate::seed_tree::error::MutationGraphError;
 use crate::seed_tree::node_name::NodeName;
 use crate::seed_tree::plot_options::plot_option::PlotOption;
 use crate::seed_tree::plot_options::PlotOptions;
+use crate::seed_tree::query::ast::{Expr, Function};
+use crate::seed_tree::query::error::QueryError;
+use crate::seed_tree::query::Query;
 use crate::seed_tree::MutationGraph;
+use crate::subcommand::result::Result;
+use crate::subcommand::util::sort_order::sort_order_of;
 use clap::ArgMatches;
-use std::collections::HashSet;
-use std::iter::FromIterator;
 
 #[allow(unused)]
 enum PrintOption {
@@ -15,7 +17,11 @@ enum PrintOption {
 }
 
 #[allow(unused)]
-pub(crate) fn filter(matches: &ArgMatches, graph: &MutationGraph, plot_options: &[PlotOption]) {
+pub(crate) fn filter(
+    matches: &ArgMatches,
+    graph: &MutationGraph,
+    plot_options: &[PlotOption],
+) -> Result<()> {
     let predecessors = match matches.value_of("PRED_ID") {
         Some(node) => Some(NodeName::from(node)),
         None => None,
@@ -25,10 +31,7 @@ pub(crate) fn filter(matches: &ArgMatches, graph: &MutationGraph, plot_options:
     let leaves = matches.is_present("leaves");
     log::info!("leaves = {:?}", leaves);
 
-    let filtered_graph = match do_filter(graph, predecessors, leaves) {
-        Ok(graph) => graph,
-        Err(why) => panic!("Failed to filter seed tree: {:?}", why),
-    };
+    let filtered_graph = do_filter(graph, predecessors, leaves)?;
 
     let print_option = if matches.is_present("meta") {
         PrintOption::PrintMetadata
@@ -40,22 +43,23 @@ pub(crate) fn filter(matches: &ArgMatches, graph: &MutationGraph, plot_options:
 
     match print_option {
         PrintOption::PrintDotGraph => {
-            match filtered_graph.dot_graph(PlotOptions::from(plot_options).unwrap()) {
-                Ok(graph) => println!("{}", graph),
-                Err(why) => panic!("Failed to convert to DOT: {:?}", why),
-            }
+            println!(
+                "{}",
+                filtered_graph.dot_graph(PlotOptions::from(plot_options)?)?
+            )
         }
         PrintOption::PrintMetadata => {
-            for node in filtered_graph.nodes() {
+            for node in filtered_graph.sorted_nodes(sort_order_of(matches)) {
                 println!("{:?}", node)
             }
         }
         PrintOption::PrintFilePath => {
-            for node in filtered_graph.nodes() {
+            for node in filtered_graph.sorted_nodes(sort_order_of(matches)) {
                 println!("{}", node.file.display())
             }
         }
     }
+    Ok(())
 }
 
 #[allow(unused)]
@@ -63,46 +67,21 @@ fn do_filter(
     graph: &MutationGraph,
     predecessors: Option<NodeName>,
     leaves: bool,
-) -> Result<MutationGraph, MutationGraphError> {
-    let base_nodes = if let Some(node) = predecessors {
-        HashSet::from_iter(graph.self_and_its_predecessors_of(&node)?.iter().cloned())
-    } else {
-        HashSet::new()
+) -> std::result::Result<MutationGraph, QueryError> {
+    // NOTE: --pred and --leaves are shorthands of `lineage(PRED_ID)` and `children(...) & leaves`
+    let base = match predecessors {
+        Some(node) => Expr::Call(Function::Lineage, Box::new(Expr::Node(node))),
+        None => Expr::Not(Box::new(Expr::All)),
     };
-
-    let filtered_nodes = if leaves {
-        let mut filtered_nodes = HashSet::new();
-        let leaves = graph.leaves();
-        for node in base_nodes {
-            filtered_nodes.insert(node);
-            if let Some(children) = graph.children_of(node) {
-                // ここがバグっぽい
-                log::trace!("node = {} -> children = {:?}", node, children);
-                for child in children.iter() {
-                    if leaves.contains(child) {
-                        log::trace!("leaf = {}", child);
-                        filtered_nodes.insert(child);
-                    }
-                }
-            }
-        }
-        filtered_nodes
+    let expr = if leaves {
+        let leaves_of_base = Expr::And(
+            Box::new(Expr::Call(Function::Children, Box::new(base.clone()))),
+            Box::new(Expr::Leaves),
+        );
+        Expr::Or(Box::new(base), Box::new(leaves_of_base))
     } else {
-        base_nodes
+        base
     };
-
-    let mut filtered_graph = MutationGraph::new();
-    for node in filtered_nodes.iter() {
-        match graph.get_node(node) {
-            Some(node) => filtered_graph.add_node(node),
-            None => return Err(MutationGraphError::NodeNotExists(node.to_string())),
-        }
-    }
-    for edge in graph.edges() {
-        if filtered_nodes.contains(&edge.parent) && filtered_nodes.contains(&edge.child) {
-            filtered_graph.add_edge(edge)
-        }
-    }
-
-    Ok(filtered_graph)
+    let query = Query { expr };
+    Ok(graph.subgraph(&query.select(graph)?))
 }
@@ -1,3 +1,7 @@
+pub(crate) mod aurora;
+pub(crate) mod campaign;
 pub(crate) mod filter;
 pub(crate) mod plot;
 pub(crate) mod preds;
+pub(crate) mod seeds;
+pub(crate) mod shell;
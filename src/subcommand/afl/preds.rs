@@ -1,4 +1,6 @@
 use crate::seed_tree::MutationGraph;
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::result::Result;
 use clap::ArgMatches;
 use log::info;
 use std::fs;
@@ -14,20 +16,14 @@ enum PrintOption {
 }
 
 #[allow(unused)]
-pub(crate) fn preds(matches: &ArgMatches, graph: &MutationGraph) {
+pub(crate) fn preds(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
     let node_name = {
         let id = match matches.value_of("ID") {
             Some(node) => node.to_string(),
-            None => {
-                eprintln!("[!] ID is not specified");
-                return;
-            }
+            None => return Err(SubcommandError::MissingArgument("ID")),
         };
         if matches.is_present("hash") {
-            graph
-                .lookup_by_file_hash(&id)
-                .expect("Failed to translate given ID to node name")
-                .clone()
+            graph.lookup_by_file_hash_prefix(&id)?.clone()
         } else {
             id
         }
@@ -38,12 +34,13 @@ pub(crate) fn preds(matches: &ArgMatches, graph: &MutationGraph) {
         Some(export_dir) => {
             if !PathBuf::from(export_dir).exists() {
                 info!("Created export directory");
-                fs::create_dir(export_dir);
+                fs::create_dir(export_dir)
+                    .map_err(|why| SubcommandError::from(why).in_file(export_dir))?;
             }
             let export_original_file = PathBuf::from(export_dir).join("original_file.txt");
             Some(
                 File::create(&export_original_file)
-                    .expect(format!("Failed to create file: {:?}", &export_original_file).as_str()),
+                    .map_err(|why| SubcommandError::from(why).in_file(&export_original_file))?,
             )
         }
         None => None,
@@ -57,38 +54,28 @@ pub(crate) fn preds(matches: &ArgMatches, graph: &MutationGraph) {
         PrintOption::PrintNodeName
     };
 
-    match graph.self_and_its_predecessors_of(&node_name) {
-        Ok(nodes) => {
-            for node in nodes {
-                let node = graph.get_node(node).unwrap();
-                match print_option {
-                    PrintOption::PrintNodeName => println!("{}", node.name),
-                    PrintOption::PrintMetadata => println!("{:?}", node),
-                    PrintOption::PrintFilePath => println!("{}", node.file.display()),
-                }
-                if !node.file.as_os_str().is_empty() {
-                    if let Some(ref export_dir) = export_dir {
-                        let copy_to = PathBuf::from(export_dir).join(&node.name);
-                        fs::copy(/* from */ &node.file, /* to */ &copy_to).expect(
-                            format!(
-                                "Failed to copy file: from={:?}, to={:?}",
-                                node.file, copy_to
-                            )
-                            .as_str(),
-                        );
-                        if let Some(ref mut export_original_file) = export_original_file {
-                            write!(
-                                export_original_file,
-                                "{} {}\n",
-                                node.hash,
-                                node.file.display()
-                            )
-                            .expect("Failed to write");
-                        }
-                    }
+    for node in graph.self_and_its_predecessors_of(&node_name)? {
+        let node = graph.get_node(node).expect("Predecessor in graph");
+        match print_option {
+            PrintOption::PrintNodeName => println!("{}", node.name),
+            PrintOption::PrintMetadata => println!("{:?}", node),
+            PrintOption::PrintFilePath => println!("{}", node.file.display()),
+        }
+        if !node.file.as_os_str().is_empty() {
+            if let Some(ref export_dir) = export_dir {
+                let copy_to = PathBuf::from(export_dir).join(&node.name);
+                fs::copy(/* from */ &node.file, /* to */ &copy_to)
+                    .map_err(|why| SubcommandError::from(why).in_file(&node.file))?;
+                if let Some(ref mut export_original_file) = export_original_file {
+                    write!(
+                        export_original_file,
+                        "{} {}\n",
+                        node.hash,
+                        node.file.display()
+                    )?;
                 }
             }
         }
-        Err(why) => eprintln!("[!] Node name={} does not exists: {:?}", node_name, why),
     }
+    Ok(())
 }
@@ -0,0 +1,50 @@
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::result::Result;
+use crate::subcommand::util::sort_order::sort_order_of;
+use clap::ArgMatches;
+use std::cmp::Reverse;
+
+/// Ranks initial seeds resolved by `--input-corpus` by numbers of their descendants and crashes among them
+#[allow(unused)]
+pub(crate) fn seeds(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
+    let seeds: Vec<&NodeName> = graph
+        .nodes()
+        .filter(|node| node.initial_seed)
+        .map(|node| &node.name)
+        .collect();
+    if seeds.is_empty() {
+        return Err(SubcommandError::MissingArgument("INPUT_CORPUS_DIR"));
+    }
+
+    let mut ranking = Vec::new();
+    for name in graph.sort_names(seeds, sort_order_of(matches)) {
+        let (mut descendants, mut crashes) = (0usize, 0usize);
+        let mut stack = vec![name];
+        while let Some(parent) = stack.pop() {
+            for child in graph.children_of(parent).unwrap_or_default() {
+                descendants += 1;
+                if graph.get_node(child).is_some_and(|node| node.crashed) {
+                    crashes += 1;
+                }
+                stack.push(child);
+            }
+        }
+        ranking.push((name, descendants, crashes));
+    }
+    // NOTE: Stable sort keeps order given by --sort among seeds with same counts
+    ranking.sort_by_key(|(_, descendants, crashes)| Reverse((*descendants, *crashes)));
+
+    println!("{:<40} {:>11} {:>7}", "seed", "descendants", "crashes");
+    for (name, descendants, crashes) in ranking.iter() {
+        println!("{:<40} {:>11} {:>7}", name, descendants, crashes);
+    }
+    eprintln!(
+        "{} initial seeds produced {} inputs and {} crashes",
+        ranking.len(),
+        ranking.iter().map(|(_, v, _)| v).sum::<usize>(),
+        ranking.iter().map(|(_, _, v)| v).sum::<usize>()
+    );
+    Ok(())
+}
@@ -0,0 +1,482 @@
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::plot_options::plot_option::PlotOption;
+use crate::seed_tree::sort_order::SortOrder;
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::afl::plot::plot;
+use crate::subcommand::afl::preds::preds;
+use crate::subcommand::common::children::children;
+use crate::subcommand::common::leaves::leaves;
+use crate::subcommand::common::nodes::nodes;
+use crate::subcommand::common::origin::origin;
+use crate::subcommand::common::pred::pred;
+use crate::subcommand::common::roots::roots;
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::libfuzzer::stats::stats_of_graph;
+use crate::subcommand::result::Result;
+use crate::subcommand::util::seed_file::{diff_seed_files, seed_file_of, seeds_dir_of, SeedFile};
+use binary_diff::BinaryDiffChunk;
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+use std::path::PathBuf;
+
+const PROMPT: &str = "seed-tree> ";
+// Words starting with this are file hashes or their prefixes (e.g. `#3a5443`)
+const HASH_PREFIX: char = '#';
+
+// NOTE: Keep in lexicographic order for completion
+const COMMANDS: &[&str] = &[
+    "children", "diff", "exit", "help", "leaves", "nodes", "origin", "plot", "preds", "roots",
+    "stats",
+];
+
+pub(crate) fn shell(
+    matches: &ArgMatches,
+    graph: &MutationGraph,
+    base_plot_options: &[PlotOption],
+) -> Result<()> {
+    // NOTE: Options given to `shell` are defaults of commands in the shell
+    let seeds_dir = matches.value_of("SEEDS_DIR");
+    let sort_order = matches.value_of("SORT");
+
+    let mut editor = Editor::<ShellHelper>::new();
+    editor.set_helper(Some(ShellHelper::new(graph)));
+    if let Some(history_file) = matches.value_of("HISTORY_FILE") {
+        // NOTE: History file does not exist on first run
+        let _ = editor.load_history(history_file);
+    }
+
+    println!(
+        "Loaded {} nodes. Type \"help\" to list commands, \"exit\" to quit.",
+        graph.node_count()
+    );
+    loop {
+        let line = match editor.readline(PROMPT) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(why) => return Err(SubcommandError::IoError(std::io::Error::other(why))),
+        };
+        let words = match split_words(&line) {
+            Some(words) => words,
+            None => {
+                eprintln!("[!] Unterminated quote");
+                continue;
+            }
+        };
+        if words.is_empty() {
+            continue;
+        }
+        editor.add_history_entry(line.as_str());
+
+        match words[0].as_str() {
+            "exit" | "quit" => break,
+            "help" => {
+                let _ = shell_app(seeds_dir, sort_order).print_long_help();
+                println!();
+                continue;
+            }
+            _ => (),
+        }
+
+        let words = match resolve_hashes(graph, words) {
+            Ok(words) => words,
+            Err(why) => {
+                eprintln!("[!] {}", why);
+                continue;
+            }
+        };
+        let line_matches = match shell_app(seeds_dir, sort_order).get_matches_from_safe(words) {
+            Ok(line_matches) => line_matches,
+            Err(why) => {
+                // NOTE: Help messages of commands are also returned as error
+                eprintln!("{}", why.message);
+                continue;
+            }
+        };
+        // NOTE: Errors of commands do not terminate the shell
+        if let Err(why) = run(&line_matches, graph, base_plot_options) {
+            eprintln!("[!] {}", why);
+        }
+    }
+
+    if let Some(history_file) = matches.value_of("HISTORY_FILE") {
+        if let Err(why) = editor.save_history(history_file) {
+            eprintln!("[!] Failed to save history to {}: {}", history_file, why);
+        }
+    }
+    Ok(())
+}
+
+fn run(
+    matches: &ArgMatches,
+    graph: &MutationGraph,
+    base_plot_options: &[PlotOption],
+) -> Result<()> {
+    if let Some(matches) = matches.subcommand_matches("preds") {
+        // NOTE: Seed contents are analyzed only when --diff or --exists is given
+        if matches.is_present("SEEDS_DIR_TO_DIFF") || matches.is_present("SEEDS_DIR_TO_EXISTS") {
+            pred(matches, graph)
+        } else {
+            preds(matches, graph)
+        }
+    } else if let Some(matches) = matches.subcommand_matches("children") {
+        children(matches, graph)
+    } else if let Some(matches) = matches.subcommand_matches("roots") {
+        roots(matches, graph)
+    } else if let Some(matches) = matches.subcommand_matches("leaves") {
+        leaves(matches, graph)
+    } else if let Some(matches) = matches.subcommand_matches("nodes") {
+        nodes(matches, graph)
+    } else if let Some(matches) = matches.subcommand_matches("diff") {
+        diff(matches, graph)
+    } else if let Some(matches) = matches.subcommand_matches("origin") {
+        let additional_file = matches.value_of("MINIMIZED_CRASH_INPUT").map(PathBuf::from);
+        origin(matches, graph, additional_file)
+    } else if let Some(matches) = matches.subcommand_matches("plot") {
+        let mut plot_options = base_plot_options.to_vec();
+        if let Some(sort_order) = matches.value_of("SORT").and_then(SortOrder::from_name) {
+            plot_options.push(PlotOption::SortBy(sort_order));
+        }
+        plot(matches, graph, plot_options.as_slice())
+    } else if let Some(matches) = matches.subcommand_matches("stats") {
+        stats_of_graph(matches, graph)
+    } else {
+        Ok(())
+    }
+}
+
+fn diff(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
+    let seeds_dir = seeds_dir_of(matches);
+    let mut seeds = Vec::new();
+    for id in ["ID_1", "ID_2"].iter() {
+        let name = match matches.value_of(id) {
+            Some(name) => NodeName::from(name),
+            None => return Err(SubcommandError::MissingArgument(*id)),
+        };
+        match seed_file_of(graph, &name, seeds_dir) {
+            Some(path) => seeds.push(SeedFile { name, path }),
+            None => {
+                return Err(SubcommandError::SeedFileNotExists(
+                    name,
+                    seeds_dir.map(|v| v.to_path_buf()),
+                ))
+            }
+        }
+    }
+
+    println!("{} -> {}", seeds[0].name, seeds[1].name);
+    let diff_chunks = diff_seed_files(&seeds[0], &seeds[1])?;
+    for chunk in diff_chunks.enhance().chunks() {
+        match chunk {
+            BinaryDiffChunk::Same(_, _) => (), // Not print
+            _ => println!("\t{}", chunk),
+        }
+    }
+    Ok(())
+}
+
+// Commands available in shell. Each line is parsed as arguments of this app.
+fn shell_app<'a, 'b>(seeds_dir: Option<&'a str>, sort_order: Option<&'a str>) -> App<'a, 'b> {
+    let seeds_arg = || {
+        let arg = Arg::with_name("SEEDS_DIR")
+            .long("seeds")
+            .help("Directory to look up seed files missing in nodes")
+            .takes_value(true);
+        match seeds_dir {
+            Some(seeds_dir) => arg.default_value(seeds_dir),
+            None => arg,
+        }
+    };
+    let sort_arg = {
+        let arg = Arg::with_name("SORT")
+            .long("sort")
+            .global(true)
+            .possible_values(SortOrder::NAMES)
+            .help("Order of nodes in listings and DOT graphs")
+            .takes_value(true);
+        match sort_order {
+            Some(sort_order) => arg.default_value(sort_order),
+            None => arg,
+        }
+    };
+
+    App::new("seed-tree")
+        .setting(AppSettings::NoBinaryName)
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .setting(AppSettings::DisableVersion)
+        .setting(AppSettings::VersionlessSubcommands)
+        .arg(sort_arg)
+        .subcommand(
+            SubCommand::with_name("preds")
+                .alias("pred")
+                .about("List predecessors of given ID and ID")
+                .arg(Arg::with_name("ID").required(true).index(1))
+                .arg(
+                    Arg::with_name("meta")
+                        .long("meta")
+                        .help("Print metadata of matched nodes"),
+                )
+                .arg(
+                    Arg::with_name("file")
+                        .long("file")
+                        .help("Print file path of matched nodes"),
+                )
+                .arg(
+                    Arg::with_name("hash").long("hash").help(
+                        "ID is meant to be file hash or its unambiguous prefix (e.g. 3a5443)",
+                    ),
+                )
+                .arg(
+                    Arg::with_name("SEEDS_DIR_TO_DIFF")
+                        .long("diff")
+                        .help("Diff seed files along predecessors"),
+                )
+                .arg(
+                    Arg::with_name("SEEDS_DIR_TO_EXISTS")
+                        .long("exists")
+                        .help("List predecessors whose seed files exist"),
+                )
+                .arg(seeds_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("children")
+                .about("List children of node ID")
+                .arg(Arg::with_name("ID").required(true).index(1)),
+        )
+        .subcommand(SubCommand::with_name("roots").about("List root nodes"))
+        .subcommand(SubCommand::with_name("leaves").about("List leaf nodes"))
+        .subcommand(
+            SubCommand::with_name("nodes")
+                .alias("ls")
+                .about("List nodes")
+                .arg(
+                    Arg::with_name("meta")
+                        .long("meta")
+                        .help("Print metadata of nodes"),
+                )
+                .arg(
+                    Arg::with_name("file")
+                        .long("file")
+                        .help("Print file path of nodes"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("diff")
+                .about("Diff seed files of ID_1 and ID_2")
+                .arg(Arg::with_name("ID_1").required(true).index(1))
+                .arg(Arg::with_name("ID_2").required(true).index(2))
+                .arg(seeds_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("origin")
+                .about("Analyze origin of each offset of node ID")
+                .arg(Arg::with_name("ID").required(true).index(1))
+                .arg(Arg::with_name("MINIMIZED_CRASH_INPUT").index(2))
+                .arg(seeds_arg())
+                .arg(
+                    Arg::with_name("plot")
+                        .long("plot")
+                        .help("Output notated seed tree in dot format"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("plot")
+                .about("Plot and save seed tree as DOT, PNG, SVG")
+                .arg(Arg::with_name("DOT_FILE").required(true).index(1))
+                .arg(Arg::with_name("ID").index(2)),
+        )
+        .subcommand(
+            SubCommand::with_name("stats")
+                .about("Count nodes, edges and mutators of seed tree")
+                .arg(
+                    Arg::with_name("TOP")
+                        .long("top")
+                        .help("Number of most used mutators to list. Default is 10")
+                        .takes_value(true),
+                ),
+        )
+}
+
+// Splits line into words. Words can be quoted with `"` to contain spaces.
+fn split_words(line: &str) -> Option<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut quoted = false;
+    for c in line.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                word.get_or_insert_with(String::new);
+            }
+            c if c.is_whitespace() && !quoted => {
+                if let Some(word) = word.take() {
+                    words.push(word);
+                }
+            }
+            c => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+    if quoted {
+        return None;
+    }
+    words.extend(word);
+    Some(words)
+}
+
+// Replaces words of `HASH_PREFIX` and file hash or its unambiguous prefix with names of their nodes
+fn resolve_hashes(graph: &MutationGraph, words: Vec<String>) -> Result<Vec<String>> {
+    words
+        .into_iter()
+        .map(|word| match word.strip_prefix(HASH_PREFIX) {
+            Some(hash) => Ok(graph.lookup_by_file_hash_prefix(hash)?.clone()),
+            None => Ok(word),
+        })
+        .collect()
+}
+
+struct ShellHelper {
+    // Node names and file hashes (with `HASH_PREFIX`) in lexicographic order to find candidates by binary search
+    words: Vec<String>,
+    commands: Vec<String>,
+}
+
+impl ShellHelper {
+    fn new(graph: &MutationGraph) -> Self {
+        let mut words: Vec<String> = graph
+            .nodes()
+            .flat_map(|node| match node.hash.is_empty() {
+                true => vec![node.name.clone()],
+                false => vec![node.name.clone(), format!("{}{}", HASH_PREFIX, node.hash)],
+            })
+            .collect();
+        words.sort();
+        words.dedup();
+
+        let commands = COMMANDS.iter().map(|v| v.to_string()).collect();
+
+        Self { words, commands }
+    }
+}
+
+fn candidates_of(words: &[String], prefix: &str) -> Vec<String> {
+    let start = words.partition_point(|v| v.as_str() < prefix);
+    words[start..]
+        .iter()
+        .take_while(|v| v.starts_with(prefix))
+        .cloned()
+        .collect()
+}
+
+impl Completer for ShellHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let start = line[..pos].rfind(char::is_whitespace).map_or(0, |v| v + 1);
+        let prefix = &line[start..pos];
+        // NOTE: First word is command and others are node names or hashes
+        let candidates = if line[..start].trim().is_empty() {
+            candidates_of(&self.commands, prefix)
+        } else {
+            candidates_of(&self.words, prefix)
+        };
+        Ok((start, candidates))
+    }
+}
+
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ShellHelper {}
+
+impl Validator for ShellHelper {}
+
+impl Helper for ShellHelper {}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::file_hash::FileHash;
+    use crate::seed_tree::hash_algorithm::HashAlgorithm;
+    use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+    use crate::seed_tree::mutation_graph_node::MutationGraphNode;
+    use crate::seed_tree::MutationGraph;
+    use crate::subcommand::afl::shell::{
+        candidates_of, resolve_hashes, shell_app, split_words, ShellHelper,
+    };
+    use std::path::Path;
+
+    #[test]
+    fn test_split_words() {
+        assert_eq!(split_words(""), Some(vec![]));
+        assert_eq!(
+            split_words("  preds  000001 --diff "),
+            Some(vec![
+                "preds".to_string(),
+                "000001".to_string(),
+                "--diff".to_string()
+            ])
+        );
+        assert_eq!(
+            split_words("plot \"a b.dot\" \"\""),
+            Some(vec![
+                "plot".to_string(),
+                "a b.dot".to_string(),
+                "".to_string()
+            ])
+        );
+        assert_eq!(split_words("plot \"a b.dot"), None);
+    }
+
+    #[test]
+    fn test_shell_completion() {
+        let mut graph = MutationGraph::new();
+        graph.add_node(&MutationGraphNode::new_with_metadata(
+            &"000001".to_string(),
+            false,
+            Path::new("queue/id:000001"),
+            &FileHash::of(HashAlgorithm::Sha1, b"seed"),
+        ));
+        graph.add_edge(&MutationGraphEdge {
+            parent: "000001".to_string(),
+            child: "000010".to_string(),
+            label: "havoc".to_string(),
+        });
+
+        let helper = ShellHelper::new(&graph);
+        let hash = "#92713d4709377111cf31f2a71986c411bd6cb5b0";
+        assert_eq!(helper.words, vec![hash, "000001", "000010"]);
+        assert_eq!(
+            candidates_of(&helper.words, "0000"),
+            vec!["000001", "000010"]
+        );
+        assert_eq!(candidates_of(&helper.words, "#92"), vec![hash]);
+        assert_eq!(candidates_of(&helper.words, "z"), Vec::<String>::new());
+        assert_eq!(candidates_of(&helper.commands, "pr"), vec!["preds"]);
+
+        let words = resolve_hashes(&graph, split_words(&format!("children {}", hash)).unwrap());
+        assert_eq!(words.unwrap(), vec!["children", "000001"]);
+        // Only words starting with `#` are taken as prefixes of hashes
+        let words = resolve_hashes(&graph, split_words("children #92713d 92713d 9").unwrap());
+        assert_eq!(words.unwrap(), vec!["children", "000001", "92713d", "9"]);
+        assert!(resolve_hashes(&graph, split_words("children #ffff").unwrap()).is_err());
+        let words = resolve_hashes(&graph, split_words("children #92713d").unwrap()).unwrap();
+        let matches = shell_app(None, None).get_matches_from_safe(words).unwrap();
+        assert_eq!(
+            matches
+                .subcommand_matches("children")
+                .unwrap()
+                .value_of("ID"),
+            Some("000001")
+        );
+    }
+}
@@ -1,19 +1,24 @@
+use crate::seed_tree::error::MutationGraphError;
 use crate::seed_tree::node_name::NodeName;
 use crate::seed_tree::MutationGraph;
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::result::Result;
+use crate::subcommand::util::sort_order::sort_order_of;
 use clap::ArgMatches;
 
 #[allow(unused)]
-pub(crate) fn children(matches: &ArgMatches, graph: &MutationGraph) {
+pub(crate) fn children(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
     let node = match matches.value_of("ID") {
         Some(node) => NodeName::from(node),
-        None => panic!("ID is not specified"),
+        None => return Err(SubcommandError::MissingArgument("ID")),
     };
     match graph.children_of(&node) {
         Some(children) => {
-            for child in children.iter() {
+            for child in graph.sort_names(children, sort_order_of(matches)) {
                 println!("{}", child);
             }
+            Ok(())
         }
-        None => eprintln!("[!] Node \"{}\" does not exists", node),
+        None => Err(MutationGraphError::NodeNotExists(node).into()),
     }
 }
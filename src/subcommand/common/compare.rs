@@ -0,0 +1,123 @@
+use crate::seed_tree::compare::{depth_histogram_of, operator_histogram_of, SeedTreeComparison};
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::result::Result;
+use crate::subcommand::util::plot_dot_graph::plot_dot_graph;
+use crate::subcommand::util::sort_order::sort_order_of;
+use clap::ArgMatches;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Compares seed tree of INPUT(s) (A) with one of OTHER(s) (B) matching nodes by contents
+#[allow(unused)]
+pub(crate) fn compare(
+    matches: &ArgMatches,
+    left: &MutationGraph,
+    right: &MutationGraph,
+) -> Result<()> {
+    let comparison = SeedTreeComparison::new(left, right)?;
+    let crashes = |graph: &MutationGraph| graph.nodes().filter(|node| node.crashed).count();
+
+    println!("{:<24} {:>10} {:>10}", "", "A", "B");
+    println!(
+        "{:<24} {:>10} {:>10}",
+        "nodes",
+        left.node_count(),
+        right.node_count()
+    );
+    println!(
+        "{:<24} {:>10} {:>10}",
+        "unique nodes",
+        comparison.left_only_nodes.len(),
+        comparison.right_only_nodes.len()
+    );
+    println!(
+        "{:<24} {:>10} {:>10}",
+        "unique edges",
+        comparison.left_only_edges.len(),
+        comparison.right_only_edges.len()
+    );
+    println!(
+        "{:<24} {:>10} {:>10}",
+        "crashes",
+        crashes(left),
+        crashes(right)
+    );
+
+    println!("nodes by depth:");
+    print_side_by_side(&depth_histogram_of(left), &depth_histogram_of(right));
+    println!("edges by operator:");
+    print_side_by_side(&operator_histogram_of(left), &operator_histogram_of(right));
+
+    println!(
+        "shared crashes reached via different lineages: {}",
+        comparison.divergent_crashes.len()
+    );
+    for crash in comparison.divergent_crashes.iter() {
+        // NOTE: Lineages end with crashes themselves
+        println!(
+            "  A: {} (depth {})\tB: {} (depth {})\tcommon: {}",
+            crash.left.last().expect("Crash in lineage"),
+            crash.left.len() - 1,
+            crash.right.last().expect("Crash in lineage"),
+            crash.right.len() - 1,
+            crash.common
+        );
+    }
+
+    if matches.is_present("list") {
+        let sort_order = sort_order_of(matches);
+        for (graph, nodes, edges, side) in [
+            (
+                left,
+                &comparison.left_only_nodes,
+                &comparison.left_only_edges,
+                "A",
+            ),
+            (
+                right,
+                &comparison.right_only_nodes,
+                &comparison.right_only_edges,
+                "B",
+            ),
+        ]
+        .iter()
+        {
+            println!("nodes only in {}:", side);
+            for name in graph.sort_names(nodes.iter().cloned(), sort_order) {
+                println!("  {}", name);
+            }
+            println!("edges only in {}:", side);
+            for edge in edges.iter() {
+                println!("  {} -> {} [{}]", edge.parent, edge.child, edge.label);
+            }
+        }
+    }
+
+    if let Some(dot_file) = matches.value_of("DOT_FILE") {
+        let dot_file = Path::new(dot_file);
+        let dot_graph_text = comparison.dot_graph(sort_order_of(matches))?;
+        fs::write(dot_file, &dot_graph_text)
+            .map_err(|why| SubcommandError::from(why).in_file(dot_file))?;
+        plot_dot_graph(&dot_graph_text, "svg", dot_file)?;
+    }
+    Ok(())
+}
+
+fn print_side_by_side<K: std::fmt::Display + Ord>(
+    left: &BTreeMap<K, usize>,
+    right: &BTreeMap<K, usize>,
+) {
+    let mut keys: Vec<&K> = left.keys().chain(right.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    for key in keys {
+        println!(
+            "  {:<22} {:>10} {:>10}",
+            key.to_string(),
+            left.get(key).cloned().unwrap_or(0),
+            right.get(key).cloned().unwrap_or(0)
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use crate::seed_tree::directed_edge::DirectedEdge;
+use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::plot_options::plot_option::PlotOption;
+use crate::seed_tree::plot_options::PlotOptions;
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::result::Result;
+use crate::subcommand::util::seed_file::{
+    diff_seed_files, open_seed_file, seed_files_of, seeds_dir_of,
+};
+use crate::subcommand::util::sort_order::sort_order_of;
+use binary_diff::{BinaryDiffAnalyzer, BinaryDiffChunk};
+use clap::ArgMatches;
+
+#[allow(unused)]
+pub(crate) fn deriv(matches: &ArgMatches, mut graph: MutationGraph) -> Result<()> {
+    let node = match matches.value_of("ID") {
+        Some(v) => v.to_string(),
+        None => return Err(SubcommandError::MissingArgument("ID")),
+    };
+    let offset = match matches.value_of("OFFSET") {
+        Some(v) => usize::from_str_radix(v, 16)
+            .map_err(|_| SubcommandError::InvalidArgument("OFFSET", v.to_string()))?,
+        None => return Err(SubcommandError::MissingArgument("OFFSET")),
+    };
+
+    let predecessors = graph.self_and_its_predecessors_of(&node)?;
+    let seeds_dir = seeds_dir_of(matches);
+    // NOTE: Seed files are looked up from file path of nodes first, then from seeds_dir
+    let seeds = seed_files_of(&graph, &predecessors, seeds_dir);
+    if seeds.len() < 2 {
+        return Err(SubcommandError::NotEnoughSeedFiles(
+            node,
+            seeds_dir.map(|v| v.to_path_buf()),
+        ));
+    }
+
+    let mut plot_option: Vec<PlotOption> = vec![PlotOption::SortBy(sort_order_of(matches))];
+
+    let mut target_offset = offset;
+    for (seed_1, seed_2) in seeds[0..seeds.len() - 1]
+        .iter()
+        .rev()
+        .zip(seeds[1..seeds.len()].iter().rev())
+    {
+        let (name_1, name_2) = (&seed_1.name, &seed_2.name);
+        log::trace!("{} -> {}", name_1, name_2);
+
+        let diff_chunks = diff_seed_files(seed_1, seed_2)?;
+        let mut analyze = BinaryDiffAnalyzer::new(&diff_chunks, open_seed_file(&seed_2.path)?);
+        let derives_from = analyze.derives_from(target_offset).map_err(|why| {
+            SubcommandError::BinaryDiffError(format!("{} -> {}: {:?}", name_1, name_2, why))
+        })?;
+
+        if matches.is_present("plot") {
+            match derives_from {
+                Some(derives_from) => {
+                    let edge = match graph.get_edge(&DirectedEdge::new(name_1, name_2)) {
+                        Some(edge) => edge,
+                        None => {
+                            log::warn!("Edge {} -> {} is not found in graph (potential bug). Added as weak edge", name_1, name_2);
+                            let edge = MutationGraphEdge {
+                                parent: name_1.clone(),
+                                child: name_2.clone(),
+                                label: NodeName::new(),
+                            };
+                            graph.add_weak_edge(&edge);
+                            edge
+                        }
+                    };
+                    match derives_from.chunk() {
+                        BinaryDiffChunk::Same(_, _) => {
+                            plot_option.push(PlotOption::HighlightEdgeWithBlue(edge))
+                        }
+                        BinaryDiffChunk::Delete(_, _) => {
+                            plot_option.push(PlotOption::HighlightEdgeWithBlue(edge));
+                            break;
+                        }
+                        BinaryDiffChunk::Insert(_, _) | BinaryDiffChunk::Replace(_, _, _) => {
+                            plot_option.push(PlotOption::HighlightEdgeWithGreen(edge));
+                            break;
+                        }
+                    }
+                }
+                None => break,
+            }
+        } else {
+            match derives_from {
+                Some(derives_from) => {
+                    println!("{} -> {}", name_1, name_2);
+                    match derives_from.original_position() {
+                        Some(original_position) => {
+                            target_offset = original_position;
+                            println!("\tat position {:#x} in original file", original_position);
+                            println!("\t{}", derives_from.chunk());
+                        }
+                        None => {
+                            println!(
+                                "\tat relative position {:#x} in chunk",
+                                derives_from.relative_position()
+                            );
+                            println!("\t{}", derives_from.chunk());
+                            break;
+                        }
+                    }
+                }
+                None => break,
+            }
+            println!()
+        }
+    }
+
+    if matches.is_present("plot") {
+        let dot_graph = graph.dot_graph(PlotOptions::from(plot_option.as_slice())?)?;
+        print!("{}", dot_graph);
+    }
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::result::Result;
+use crate::subcommand::util::sort_order::sort_order_of;
+use clap::ArgMatches;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+/// Lists inputs having same contents found multiple times in seed tree (e.g. rediscovered via different lineages
+/// or imported by sync), most rediscovered contents first. Initial seeds are not counted as findings.
+#[allow(unused)]
+pub(crate) fn dups(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
+    let sort_order = sort_order_of(matches);
+    let mut groups: Vec<Vec<&NodeName>> = graph
+        .duplicates()
+        .into_iter()
+        .map(|group| {
+            let group = group
+                .into_iter()
+                .filter(|name| graph.get_node(name).is_some_and(|node| !node.initial_seed));
+            graph.sort_names(group, sort_order)
+        })
+        .filter(|group| group.len() > 1)
+        .collect();
+    // NOTE: Stable sort keeps order of first nodes among groups with same size
+    groups.sort_by_key(|group| Reverse(group.len()));
+
+    let (mut rediscoveries, mut divergent) = (0, 0);
+    for group in groups.iter() {
+        let node = graph.get_node(group[0]).expect("Node in graph");
+        println!("{} ({} inputs)", node.hash, group.len());
+        let mut parents = HashSet::new();
+        for name in group.iter() {
+            let parent = graph.parent_of(name);
+            parents.insert(parent);
+            let sources = graph.sources_of(name);
+            println!(
+                "  {:<40} rank {:>4}  parent {:<40} {}",
+                name,
+                graph.rank_of(name)?,
+                parent.map_or("-", |v| v.as_str()),
+                sources
+                    .iter()
+                    .map(|v| v.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(",")
+            );
+        }
+        rediscoveries += group.len() - 1;
+        if parents.len() > 1 {
+            divergent += 1;
+        }
+    }
+    eprintln!(
+        "{} contents are rediscovered {} times ({} of them via different parents)",
+        groups.len(),
+        rediscoveries,
+        divergent
+    );
+    Ok(())
+}
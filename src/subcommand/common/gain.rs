@@ -0,0 +1,47 @@
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::result::Result;
+use clap::ArgMatches;
+
+/// Reports coverage gained by each node in lineage of NODE_NAME from root, using stats given by `--log`
+#[allow(unused)]
+pub(crate) fn gain(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
+    // NOTE: NODE_NAME is required by clap
+    let node = NodeName::from(matches.value_of("NODE_NAME").unwrap());
+    let lineage = graph.self_and_its_predecessors_of(&node)?;
+
+    let (mut cov_gain, mut ft_gain, mut known) = (0, 0, 0);
+    println!(
+        "{:<40} {:>10} {:>6} {:>6} {:>8} {:>6} {:>6}",
+        "node", "execs", "cov", "+cov", "ft", "+ft", "corp"
+    );
+    for name in lineage.iter() {
+        // NOTE: Nodes get stats only if discovered at NEW lines (e.g. initial inputs do not)
+        match graph.get_node(name).and_then(|v| v.stats) {
+            Some(stats) => {
+                println!(
+                    "{:<40} {:>10} {:>6} {:>6} {:>8} {:>6} {:>6}",
+                    name,
+                    stats.execs,
+                    stats.cov,
+                    stats.cov_gain,
+                    stats.ft,
+                    stats.ft_gain,
+                    stats.corpus_units
+                );
+                cov_gain += stats.cov_gain;
+                ft_gain += stats.ft_gain;
+                known += 1;
+            }
+            None => println!("{:<40} {:>10}", name, "-"),
+        }
+    }
+    eprintln!(
+        "Lineage gained {} cov and {} ft ({} of {} nodes have stats)",
+        cov_gain,
+        ft_gain,
+        known,
+        lineage.len()
+    );
+    Ok(())
+}
@@ -1,12 +1,12 @@
-use crate::seed_tree::node_name::NodeName;
 use crate::seed_tree::MutationGraph;
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
+use crate::subcommand::result::Result;
+use crate::subcommand::util::sort_order::sort_order_of;
+use clap::ArgMatches;
 
-pub(crate) fn leaves(graph: MutationGraph) {
+pub(crate) fn leaves(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
     let leaves = graph.leaves();
-    let heap: BinaryHeap<Reverse<&&NodeName>> = leaves.iter().map(|v| Reverse(v)).collect();
-    for name in heap.into_iter_sorted() {
-        println!("{}", name.0)
+    for name in graph.sort_names(leaves, sort_order_of(matches)) {
+        println!("{}", name)
     }
+    Ok(())
 }
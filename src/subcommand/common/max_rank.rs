@@ -1,9 +1,10 @@
 use crate::seed_tree::error::MutationGraphError;
 use crate::seed_tree::node_name::NodeName;
 use crate::seed_tree::MutationGraph;
+use crate::subcommand::result::Result;
+use crate::subcommand::util::sort_order::sort_order_of;
 use clap::ArgMatches;
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashSet};
+use std::collections::HashSet;
 
 #[allow(unused)]
 enum PrintOption {
@@ -13,7 +14,7 @@ enum PrintOption {
 }
 
 #[allow(unused)]
-pub(crate) fn max_rank(matches: &ArgMatches, graph: &MutationGraph) {
+pub(crate) fn max_rank(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
     let print_option = if matches.is_present("meta") {
         PrintOption::PrintMetadata
     } else if matches.is_present("file") {
@@ -21,24 +22,23 @@ pub(crate) fn max_rank(matches: &ArgMatches, graph: &MutationGraph) {
     } else {
         PrintOption::PrintNodeName
     };
-    let max_rank_nodes = match get_max_rank_nodes(graph) {
-        Ok(v) => v,
-        Err(why) => panic!("Failed to get nodes at max rank: {:?}", why),
-    };
-    let heap: BinaryHeap<Reverse<&&NodeName>> = max_rank_nodes.iter().map(|v| Reverse(v)).collect();
-    for name in heap.into_iter_sorted() {
+    let max_rank_nodes = get_max_rank_nodes(graph)?;
+    for name in graph.sort_names(max_rank_nodes, sort_order_of(matches)) {
+        // NOTE: Nodes at max rank are taken from graph, so they always exist
+        let node = graph.get_node(name).expect("Node at max rank");
         match print_option {
-            PrintOption::PrintNodeName => println!("{}", name.0),
-            PrintOption::PrintMetadata => println!("{:?}", graph.get_node(name.0).unwrap()),
-            PrintOption::PrintFilePath => {
-                println!("{}", graph.get_node(name.0).unwrap().file.display())
-            }
+            PrintOption::PrintNodeName => println!("{}", name),
+            PrintOption::PrintMetadata => println!("{:?}", node),
+            PrintOption::PrintFilePath => println!("{}", node.file.display()),
         }
     }
+    Ok(())
 }
 
 #[allow(unused)]
-fn get_max_rank_nodes(graph: &MutationGraph) -> Result<HashSet<&NodeName>, MutationGraphError> {
+fn get_max_rank_nodes(
+    graph: &MutationGraph,
+) -> std::result::Result<HashSet<&NodeName>, MutationGraphError> {
     let mut max_rank = 0;
     let mut max_rank_nodes = HashSet::new();
     for leaf in graph.leaves() {
@@ -0,0 +1,56 @@
+use crate::seed_tree::snapshot::save_snapshot;
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::result::Result;
+use clap::ArgMatches;
+use std::path::Path;
+
+/// Merges seed trees of `sources` (e.g. INPUT and SOURCE(s)) unifying nodes with same contents,
+/// and saves merged seed tree to SNAPSHOT_FILE
+#[allow(unused)]
+pub(crate) fn merge(matches: &ArgMatches, sources: &[(String, MutationGraph)]) -> Result<()> {
+    let snapshot_file = match matches.value_of("SNAPSHOT_FILE") {
+        Some(v) => Path::new(v),
+        None => return Err(SubcommandError::MissingArgument("SNAPSHOT_FILE")),
+    };
+
+    let mut merged = MutationGraph::new();
+    for (source, graph) in sources.iter() {
+        merged.merge(graph, source);
+    }
+
+    println!(
+        "{:<40} {:>10} {:>10} {:>10}",
+        "source", "nodes", "unique", "crashes"
+    );
+    for (source, graph) in sources.iter() {
+        // NOTE: Nodes only in one source are unique to the source
+        let unique = merged
+            .nodes()
+            .filter(|node| merged.sources_of(&node.name) == [source])
+            .count();
+        let crashes = graph.nodes().filter(|node| node.crashed).count();
+        println!(
+            "{:<40} {:>10} {:>10} {:>10}",
+            source,
+            graph.node_count(),
+            unique,
+            crashes
+        );
+    }
+    println!(
+        "{:<40} {:>10} {:>10} {:>10}",
+        "merged",
+        merged.node_count(),
+        "",
+        merged.nodes().filter(|node| node.crashed).count()
+    );
+
+    save_snapshot(&merged, snapshot_file)
+        .map_err(|why| SubcommandError::from(why).in_file(snapshot_file))?;
+    log::info!(
+        "Saved merged seed tree to file \"{}\"",
+        snapshot_file.display()
+    );
+    Ok(())
+}
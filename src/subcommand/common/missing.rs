@@ -0,0 +1,33 @@
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::result::Result;
+use crate::subcommand::util::seed_file::{seed_file_of, seeds_dir_of};
+use crate::subcommand::util::sort_order::sort_order_of;
+use clap::ArgMatches;
+
+/// Lists nodes whose seed files are not found on disk, among lineage of NODE_NAME or whole graph
+#[allow(unused)]
+pub(crate) fn missing(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
+    let names: Vec<&NodeName> = match matches.value_of("NODE_NAME") {
+        Some(node) => graph.self_and_its_predecessors_of(&NodeName::from(node))?,
+        None => graph.nodes().map(|v| &v.name).collect(),
+    };
+    let seeds_dir = seeds_dir_of(matches);
+
+    let total = names.len();
+    let missing: Vec<&NodeName> = names
+        .into_iter()
+        .filter(|name| seed_file_of(graph, name, seeds_dir).is_none())
+        .collect();
+    // NOTE: Lineage is printed from root as `pred` does
+    let missing = if matches.is_present("NODE_NAME") {
+        missing
+    } else {
+        graph.sort_names(missing, sort_order_of(matches))
+    };
+    for name in missing.iter() {
+        println!("{}", name);
+    }
+    eprintln!("{} of {} nodes are missing on disk", missing.len(), total);
+    Ok(())
+}
@@ -1,5 +1,14 @@
 pub(crate) mod children;
+pub(crate) mod compare;
+pub(crate) mod deriv;
+pub(crate) mod dups;
+pub(crate) mod gain;
 pub(crate) mod leaves;
 pub(crate) mod max_rank;
+pub(crate) mod merge;
+pub(crate) mod missing;
 pub(crate) mod nodes;
+pub(crate) mod origin;
+pub(crate) mod pred;
 pub(crate) mod roots;
+pub(crate) mod save;
@@ -1,4 +1,6 @@
 use crate::seed_tree::MutationGraph;
+use crate::subcommand::result::Result;
+use crate::subcommand::util::sort_order::sort_order_of;
 use clap::ArgMatches;
 
 enum PrintOption {
@@ -8,7 +10,7 @@ enum PrintOption {
 }
 
 #[allow(unused)]
-pub(crate) fn nodes(matches: &ArgMatches, graph: &MutationGraph) {
+pub(crate) fn nodes(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
     let print_option = if matches.is_present("meta") {
         PrintOption::PrintMetadata
     } else if matches.is_present("file") {
@@ -16,11 +18,12 @@ pub(crate) fn nodes(matches: &ArgMatches, graph: &MutationGraph) {
     } else {
         PrintOption::PrintNodeName
     };
-    for node in graph.nodes() {
+    for node in graph.sorted_nodes(sort_order_of(matches)) {
         match print_option {
             PrintOption::PrintNodeName => println!("{}", node.name),
             PrintOption::PrintMetadata => println!("{:?}", node),
             PrintOption::PrintFilePath => println!("{}", node.file.display()),
         }
     }
+    Ok(())
 }
@@ -2,111 +2,109 @@ use crate::seed_tree::node_name::NodeName;
 use crate::seed_tree::plot_options::plot_option::PlotOption;
 use crate::seed_tree::plot_options::PlotOptions;
 use crate::seed_tree::MutationGraph;
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::result::Result;
+use crate::subcommand::util::seed_file::{
+    diff_seed_files, open_seed_file, seed_file_of, seed_files_of, seeds_dir_of, SeedFile,
+};
+use crate::subcommand::util::sort_order::sort_order_of;
 use binary_diff::{BinaryDiff, BinaryDiffAnalyzer, BinaryDiffChunk};
 use clap::ArgMatches;
 use std::cmp::Ordering;
 use std::cmp::Ordering::Equal;
 use std::collections::HashSet;
 use std::fmt;
-use std::fs::File;
 use std::io::{BufReader, Read, Seek};
 use std::iter::FromIterator;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 
 #[allow(unused)]
 pub(crate) fn origin(
     matches: &ArgMatches,
-    graph: MutationGraph,
+    graph: &MutationGraph,
     minimized_crash_input: Option<PathBuf>,
-) {
-    let node = match matches.value_of("NODE_NAME") {
+) -> Result<()> {
+    let node = match matches.value_of("ID") {
         Some(v) => NodeName::from(v),
+        None => return Err(SubcommandError::MissingArgument("ID")),
+    };
+
+    let seeds_dir = seeds_dir_of(matches);
+
+    let predecessors = graph.self_and_its_predecessors_of(&node)?;
+    if predecessors.len() < 2 {
+        return Err(SubcommandError::NoPredecessors(node));
+    }
+
+    // NOTE: Seed files are looked up from file path of nodes first, then from seeds_dir
+    let seeds = seed_files_of(graph, &predecessors, seeds_dir);
+    let node_file = match seed_file_of(graph, &node, seeds_dir) {
+        Some(node_file) => node_file,
         None => {
-            eprintln!("[!] NODE_NAME is not specified");
-            return;
+            return Err(SubcommandError::SeedFileNotExists(
+                node,
+                seeds_dir.map(|v| v.to_path_buf()),
+            ))
         }
     };
 
-    let seeds_dir = if let Some(seeds_dir_string) = matches.value_of("SEEDS_DIR") {
-        Path::new(seeds_dir_string)
-    } else {
-        eprintln!("[!] SEEDS_DIR is not specified");
-        return;
+    let node_file_size = std::fs::metadata(&node_file)
+        .map_err(|why| SubcommandError::from(why).in_file(&node_file))?
+        .len() as usize;
+
+    let ignored_offsets = match minimized_crash_input {
+        Some(ref minimized_crash_input) => calculate_deleted_offsets(
+            BufReader::new(open_seed_file(&node_file)?),
+            BufReader::new(open_seed_file(minimized_crash_input)?),
+        )?,
+        None => HashSet::new(),
     };
+    if let Some(ref minimized_crash_input) = minimized_crash_input {
+        let mut sorted_ignored_offsets: Vec<&Offset> = ignored_offsets.iter().collect();
+        sorted_ignored_offsets.sort();
+        log::info!(
+            "Offsets {:?} of {} are ignored",
+            sorted_ignored_offsets,
+            minimized_crash_input.display()
+        )
+    }
+
+    let mut origins: Vec<Origin> = Vec::new();
+    for offset in (0..node_file_size).filter(|offset| !ignored_offsets.contains(offset)) {
+        if let Some(origin) = find_origin_of(offset, &seeds)? {
+            origins.push(origin);
+        }
+    }
 
-    match graph.self_and_its_predecessors_of(&node) {
-        Ok(predecessors) => {
-            if predecessors.len() > 1 {
-                let seeds: Vec<NodeName> = predecessors
-                    .iter()
-                    .filter(|name| seeds_dir.join(&name).exists())
-                    .map(|v| NodeName::from(*v))
-                    .collect();
-
-                let node_file_size =
-                    std::fs::metadata(seeds_dir.join(&node)).unwrap().len() as usize;
-
-                let ignored_offsets = match minimized_crash_input {
-                    Some(ref minimized_crash_input) => calculate_deleted_offsets(
-                        BufReader::new(File::open(seeds_dir.join(node.clone())).unwrap()),
-                        BufReader::new(File::open(minimized_crash_input).unwrap()),
+    if matches.is_present("plot") {
+        let mut plot_options: Vec<PlotOption> = origins
+            .iter()
+            .map(|origin| {
+                PlotOption::NotateTo(
+                    origin.node.clone(),
+                    format!(
+                        "[{:x}] ← {}({:x})",
+                        origin.of_offset,
+                        origin.chunk.name(),
+                        origin.position
                     ),
-                    None => HashSet::new(),
-                };
-                if let Some(ref minimized_crash_input) = minimized_crash_input {
-                    let mut sorted_ignored_offsets: Vec<&Offset> = ignored_offsets.iter().collect();
-                    sorted_ignored_offsets.sort();
-                    log::info!(
-                        "Offsets {:?} of {} are ignored",
-                        sorted_ignored_offsets,
-                        minimized_crash_input.display()
-                    )
-                }
+                )
+            })
+            .collect();
 
-                let mut origins: Vec<Origin> = (0..node_file_size)
-                    .filter(|offset| !ignored_offsets.contains(offset))
-                    .map(|offset| find_origin_of(offset, seeds_dir, &seeds))
-                    .filter(|v| v.is_some())
-                    .map(|v| v.unwrap())
-                    .collect();
-
-                if matches.is_present("plot") {
-                    let mut plot_options: Vec<PlotOption> = origins
-                        .iter()
-                        .map(|origin| {
-                            PlotOption::NotateTo(
-                                origin.node.clone(),
-                                format!(
-                                    "[{:x}] ← {}({:x})",
-                                    origin.of_offset,
-                                    origin.chunk.name(),
-                                    origin.position
-                                ),
-                            )
-                        })
-                        .collect();
-
-                    plot_options.push(PlotOption::HighlightEdgesFromRootTo(node.clone()));
-
-                    let dot = graph
-                        .dot_graph(PlotOptions::from(plot_options.as_slice()).unwrap())
-                        .unwrap();
-
-                    println!("{}", dot);
-                } else {
-                    origins.sort();
-                    for ref origin in origins {
-                        println!("{}", origin);
-                    }
-                }
-            } else {
-                eprintln!("[!] Given node does not have predecessors: sha1={}", node);
-            }
-        }
-        Err(why) => {
-            eprintln!("[!] Failed to get predecessors of {}: {:?}", node, why)
+        plot_options.push(PlotOption::HighlightEdgesFromRootTo(node.clone()));
+        plot_options.push(PlotOption::SortBy(sort_order_of(matches)));
+
+        let dot = graph.dot_graph(PlotOptions::from(plot_options.as_slice())?)?;
+
+        println!("{}", dot);
+    } else {
+        origins.sort();
+        for ref origin in origins {
+            println!("{}", origin);
         }
     }
+    Ok(())
 }
 
 type Offset = usize;
@@ -158,10 +156,12 @@ impl Ord for Origin {
 fn calculate_deleted_offsets<R: Read + Seek>(
     mut original: BufReader<R>,
     mut patched: BufReader<R>,
-) -> HashSet<Offset> {
-    let diff = BinaryDiff::new(&mut original, &mut patched).unwrap();
+) -> Result<HashSet<Offset>> {
+    let diff = BinaryDiff::new(&mut original, &mut patched)
+        .map_err(|why| SubcommandError::BinaryDiffError(format!("{:?}", why)))?;
     log::trace!("diff = {:?}", diff);
-    diff.enhance()
+    Ok(diff
+        .enhance()
         .chunks()
         .iter()
         .map(|v| match v {
@@ -187,57 +187,60 @@ fn calculate_deleted_offsets<R: Read + Seek>(
             }
             _ => HashSet::new(),
         })
-        .fold(HashSet::new(), |acc, v| acc.union(&v).cloned().collect())
+        .fold(HashSet::new(), |acc, v| acc.union(&v).cloned().collect()))
 }
 
-fn find_origin_of(offset: usize, seeds_dir: &Path, seeds: &Vec<NodeName>) -> Option<Origin> {
+fn find_origin_of(offset: usize, seeds: &[SeedFile]) -> Result<Option<Origin>> {
+    if seeds.len() < 2 {
+        return Ok(None);
+    }
+
     let mut target_offset = offset;
-    for (i, (name_1, name_2)) in seeds[0..seeds.len() - 1]
+    for (i, (seed_1, seed_2)) in seeds[0..seeds.len() - 1]
         .iter()
         .rev()
         .zip(seeds[1..seeds.len()].iter().rev())
         .enumerate()
     {
-        log::trace!("{} -> {}", name_1, name_2);
+        log::trace!("{} -> {}", seed_1.name, seed_2.name);
 
         // TODO: Memorize to reduce redundant calculation
-        let diff = {
-            let file_1 = std::fs::File::open(seeds_dir.join(&name_1)).unwrap();
-            let file_2 = std::fs::File::open(seeds_dir.join(&name_2)).unwrap();
-
-            BinaryDiff::new(&mut BufReader::new(file_1), &mut BufReader::new(file_2)).unwrap()
-        };
+        let diff = diff_seed_files(seed_1, seed_2)?;
         let enhanced_diff = diff.enhance();
-        let mut analyze = {
-            let patched_file = std::fs::File::open(seeds_dir.join(&name_2)).unwrap();
-            BinaryDiffAnalyzer::new(&enhanced_diff, patched_file)
-        };
+        let mut analyze = BinaryDiffAnalyzer::new(&enhanced_diff, open_seed_file(&seed_2.path)?);
 
-        match analyze.derives_from(target_offset).unwrap() {
+        let derives_from = analyze.derives_from(target_offset).map_err(|why| {
+            SubcommandError::BinaryDiffError(format!(
+                "{} -> {}: {:?}",
+                seed_1.name, seed_2.name, why
+            ))
+        })?;
+        match derives_from {
             Some(derives_from) => match derives_from.original_position() {
                 Some(position) => target_offset = position,
                 None => {
                     let chunk = derives_from.chunk();
-                    return Some(Origin {
+                    return Ok(Some(Origin {
                         of_offset: offset,
                         depth: i + 1,
-                        node: name_2.clone(), // Derives from this patched binary
+                        node: seed_2.name.clone(), // Derives from this patched binary
                         position: derives_from.patched_position(),
                         chunk: chunk.clone(),
-                    });
+                    }));
                 }
             },
-            None => return None,
+            None => return Ok(None),
         }
     }
-    None
+    Ok(None)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::seed_tree::node_name::NodeName;
     use crate::seed_tree::parser::libfuzzer::parse_libfuzzer_mutation_graph_file;
-    use crate::subcommand::libfuzzer::origin::{calculate_deleted_offsets, find_origin_of};
+    use crate::subcommand::common::origin::{calculate_deleted_offsets, find_origin_of};
+    use crate::subcommand::util::seed_file::seed_files_of;
     use std::collections::HashSet;
     use std::fs::File;
     use std::io::BufReader;
@@ -260,7 +263,8 @@ mod tests {
         let result = calculate_deleted_offsets(
             BufReader::new(crash_input),
             BufReader::new(minimized_crash_input),
-        );
+        )
+        .unwrap();
         // let answer = HashSet::from_iter([1, 2, 3, 8, 9, 13, 14, 15, 16, 18].iter().cloned());
         let answer = HashSet::from_iter([1, 2, 3, 8, 9, 12, 13, 14, 15, 16, 18].iter().cloned());
 
@@ -278,67 +282,64 @@ mod tests {
             "test/sample/mutation_graph_file/fuzzer-test-suite-openssl-1.0.1f.dot",
         ))
         .unwrap();
-        let seeds = graph
+        let predecessors = graph
             .self_and_its_predecessors_of(&NodeName::from(
                 "c298122410da09836c59484e995c287294c31394",
             ))
-            .unwrap()
-            .iter()
-            .filter(|name| seeds_dir.join(&name).exists())
-            .map(|v| NodeName::from(v.clone()))
-            .collect();
+            .unwrap();
+        let seeds = seed_files_of(&graph, &predecessors, Some(seeds_dir));
 
         // On far node from target node
         assert_eq!(
-            find_origin_of(0x14, seeds_dir, &seeds).unwrap().node,
+            find_origin_of(0x14, &seeds).unwrap().unwrap().node,
             NodeName::from("99878cf124782dc6d21f079bb29e0dba54606bbb")
         );
         assert_eq!(
-            find_origin_of(0x14, seeds_dir, &seeds).unwrap().position,
+            find_origin_of(0x14, &seeds).unwrap().unwrap().position,
             0x1e
         );
         assert_eq!(
-            find_origin_of(0x16, seeds_dir, &seeds).unwrap().position,
+            find_origin_of(0x16, &seeds).unwrap().unwrap().position,
             0x24
         );
         assert_eq!(
-            find_origin_of(0x17, seeds_dir, &seeds).unwrap().position,
+            find_origin_of(0x17, &seeds).unwrap().unwrap().position,
             0x26
         );
         assert_eq!(
-            find_origin_of(0x18, seeds_dir, &seeds).unwrap().position,
+            find_origin_of(0x18, &seeds).unwrap().unwrap().position,
             0x29
         );
 
         // On in front of target node
         assert_eq!(
-            find_origin_of(0x15, seeds_dir, &seeds).unwrap().node,
+            find_origin_of(0x15, &seeds).unwrap().unwrap().node,
             NodeName::from("76e46ec1efcdcb854486037defc3e777a62524ed")
         );
         assert_eq!(
-            find_origin_of(0x15, seeds_dir, &seeds).unwrap().position,
+            find_origin_of(0x15, &seeds).unwrap().unwrap().position,
             0x15
         );
         assert_eq!(
-            find_origin_of(0x19, seeds_dir, &seeds).unwrap().position,
+            find_origin_of(0x19, &seeds).unwrap().unwrap().position,
             0x19
         );
         assert_eq!(
-            find_origin_of(0x1b, seeds_dir, &seeds).unwrap().position,
+            find_origin_of(0x1b, &seeds).unwrap().unwrap().position,
             0x1b
         );
         assert_eq!(
-            find_origin_of(0x1c, seeds_dir, &seeds).unwrap().position,
+            find_origin_of(0x1c, &seeds).unwrap().unwrap().position,
             0x1c
         );
 
         // On target node
         assert_eq!(
-            find_origin_of(0x1a, seeds_dir, &seeds).unwrap().node,
+            find_origin_of(0x1a, &seeds).unwrap().unwrap().node,
             NodeName::from("c298122410da09836c59484e995c287294c31394")
         );
         assert_eq!(
-            find_origin_of(0x1a, seeds_dir, &seeds).unwrap().position,
+            find_origin_of(0x1a, &seeds).unwrap().unwrap().position,
             0x1a
         );
     }
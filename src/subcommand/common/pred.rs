@@ -0,0 +1,68 @@
+use crate::seed_tree::directed_edge::DirectedEdge;
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::result::Result;
+use crate::subcommand::util::seed_file::{diff_seed_files, seed_files_of, seeds_dir_of};
+use binary_diff::BinaryDiffChunk;
+use clap::ArgMatches;
+
+#[allow(unused)]
+pub(crate) fn pred(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
+    let node = match matches.value_of("ID") {
+        Some(node) => node.to_string(),
+        None => return Err(SubcommandError::MissingArgument("ID")),
+    };
+
+    let predecessors = graph.self_and_its_predecessors_of(&node)?;
+    let seeds_dir = seeds_dir_of(matches);
+    log::info!("seeds_dir = {:?}", seeds_dir);
+    if !matches.is_present("SEEDS_DIR_TO_EXISTS") && !matches.is_present("SEEDS_DIR_TO_DIFF") {
+        let mut parent: Option<&NodeName> = None;
+        for name in predecessors.iter() {
+            match parent {
+                Some(parent) if matches.is_present("jobs") => {
+                    let jobs = graph.jobs_of(&DirectedEdge::new(parent, name));
+                    println!("{}\t{:?}", name, jobs)
+                }
+                _ => println!("{}", name),
+            }
+            parent = Some(name);
+        }
+        return Ok(());
+    }
+
+    // NOTE: Seed files are looked up from file path of nodes first, then from seeds_dir
+    let seeds = seed_files_of(graph, &predecessors, seeds_dir);
+    log::info!("seeds = {:?}", seeds);
+
+    if matches.is_present("SEEDS_DIR_TO_EXISTS") {
+        for seed in seeds.iter() {
+            println!("{}", seed.name);
+        }
+    }
+    if matches.is_present("SEEDS_DIR_TO_DIFF") {
+        // NOTE: Diffs need a pair of seed files at least
+        if seeds.len() < 2 {
+            return Err(SubcommandError::NotEnoughSeedFiles(
+                node,
+                seeds_dir.map(|v| v.to_path_buf()),
+            ));
+        }
+        for (seed_1, seed_2) in seeds[0..seeds.len() - 1]
+            .iter()
+            .zip(seeds[1..seeds.len()].iter())
+        {
+            println!("{} -> {}", seed_1.name, seed_2.name);
+            let diff_chunks = diff_seed_files(seed_1, seed_2)?;
+            for chunk in diff_chunks.enhance().chunks() {
+                match chunk {
+                    BinaryDiffChunk::Same(_, _) => (), // Not print
+                    _ => println!("\t{}", chunk),
+                }
+            }
+            println!()
+        }
+    }
+    Ok(())
+}
@@ -1,12 +1,12 @@
-use crate::seed_tree::node_name::NodeName;
 use crate::seed_tree::MutationGraph;
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
+use crate::subcommand::result::Result;
+use crate::subcommand::util::sort_order::sort_order_of;
+use clap::ArgMatches;
 
-pub(crate) fn roots(graph: MutationGraph) {
-    let leaves = graph.roots();
-    let heap: BinaryHeap<Reverse<&&NodeName>> = leaves.iter().map(|v| Reverse(v)).collect();
-    for name in heap.into_iter_sorted() {
-        println!("{}", name.0)
+pub(crate) fn roots(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
+    let roots = graph.roots();
+    for name in graph.sort_names(roots, sort_order_of(matches)) {
+        println!("{}", name)
     }
+    Ok(())
 }
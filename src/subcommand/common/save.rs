@@ -0,0 +1,18 @@
+use crate::seed_tree::snapshot::save_snapshot;
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::result::Result;
+use clap::ArgMatches;
+use std::path::Path;
+
+pub(crate) fn save(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
+    let snapshot_file = match matches.value_of("SNAPSHOT_FILE") {
+        Some(v) => Path::new(v),
+        None => return Err(SubcommandError::MissingArgument("SNAPSHOT_FILE")),
+    };
+
+    save_snapshot(graph, snapshot_file)
+        .map_err(|why| SubcommandError::from(why).in_file(snapshot_file))?;
+    log::info!("Saved seed tree to file \"{}\"", snapshot_file.display());
+    Ok(())
+}
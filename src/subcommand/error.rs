@@ -0,0 +1,127 @@
+use crate::seed_tree::error::MutationGraphError;
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::parser::error::ParseError;
+use crate::seed_tree::plot_options::error::PlotOptionError;
+use crate::seed_tree::query::error::QueryError;
+use crate::seed_tree::snapshot::error::SnapshotError;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+type ArgName = &'static str;
+
+#[derive(Debug)]
+pub(crate) enum SubcommandError {
+    IoError(io::Error),
+    MutationGraphError(MutationGraphError),
+    ParseError(ParseError),
+    PlotOptionError(PlotOptionError),
+    QueryError(QueryError),
+    SnapshotError(SnapshotError),
+    // NOTE: Error type of binary-diff is kept as message since it is not exported
+    BinaryDiffError(String),
+    MissingArgument(ArgName),
+    InvalidArgument(ArgName, String),
+    SeedFileNotExists(NodeName, Option<PathBuf>),
+    // Less than 2 seed files of predecessors are found to be diffed
+    NotEnoughSeedFiles(NodeName, Option<PathBuf>),
+    // Given node must have at least one predecessor to be analyzed
+    NoPredecessors(NodeName),
+    // Failed to spawn or run external command (e.g. graphviz)
+    CommandFailed(&'static str, io::Error),
+    // Error on accessing file
+    InFile(PathBuf, Box<SubcommandError>),
+}
+
+impl SubcommandError {
+    pub(crate) fn in_file<P: AsRef<Path>>(self, path: P) -> Self {
+        Self::InFile(path.as_ref().to_path_buf(), Box::new(self))
+    }
+}
+
+impl fmt::Display for SubcommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IoError(error) => write!(f, "{}", error),
+            Self::MutationGraphError(error) => write!(f, "{}", error),
+            Self::ParseError(error) => write!(f, "{}", error),
+            Self::PlotOptionError(error) => write!(f, "{}", error),
+            Self::QueryError(error) => write!(f, "invalid query: {}", error),
+            Self::SnapshotError(error) => write!(f, "{}", error),
+            Self::BinaryDiffError(message) => write!(f, "failed to diff files: {}", message),
+            Self::MissingArgument(name) => write!(f, "{} is not specified", name),
+            Self::InvalidArgument(name, value) => write!(f, "invalid {}: {:?}", name, value),
+            Self::SeedFileNotExists(name, Some(seeds_dir)) => write!(
+                f,
+                "seed file of node {:?} is not found (SEEDS_DIR={})",
+                name,
+                seeds_dir.display()
+            ),
+            Self::SeedFileNotExists(name, None) => {
+                write!(f, "seed file of node {:?} is not found", name)
+            }
+            Self::NotEnoughSeedFiles(name, seeds_dir) => write!(
+                f,
+                "less than 2 seed files of predecessors of node {:?} are found (SEEDS_DIR={:?})",
+                name, seeds_dir
+            ),
+            Self::NoPredecessors(name) => write!(f, "node {:?} does not have predecessors", name),
+            Self::CommandFailed(command, error) => {
+                write!(f, "failed to run {:?}: {}", command, error)
+            }
+            Self::InFile(path, error) => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for SubcommandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::IoError(error) => Some(error),
+            Self::MutationGraphError(error) => Some(error),
+            Self::ParseError(error) => Some(error),
+            Self::PlotOptionError(error) => Some(error),
+            Self::QueryError(error) => Some(error),
+            Self::SnapshotError(error) => Some(error),
+            Self::CommandFailed(_, error) => Some(error),
+            Self::InFile(_, error) => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SubcommandError {
+    fn from(error: io::Error) -> Self {
+        Self::IoError(error)
+    }
+}
+
+impl From<MutationGraphError> for SubcommandError {
+    fn from(error: MutationGraphError) -> Self {
+        Self::MutationGraphError(error)
+    }
+}
+
+impl From<ParseError> for SubcommandError {
+    fn from(error: ParseError) -> Self {
+        Self::ParseError(error)
+    }
+}
+
+impl From<PlotOptionError> for SubcommandError {
+    fn from(error: PlotOptionError) -> Self {
+        Self::PlotOptionError(error)
+    }
+}
+
+impl From<QueryError> for SubcommandError {
+    fn from(error: QueryError) -> Self {
+        Self::QueryError(error)
+    }
+}
+
+impl From<SnapshotError> for SubcommandError {
+    fn from(error: SnapshotError) -> Self {
+        Self::SnapshotError(error)
+    }
+}
@@ -0,0 +1,91 @@
+use crate::seed_tree::weak_edge::{WeakEdge, WeakEdgeReason};
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::result::Result;
+use clap::ArgMatches;
+
+#[allow(unused)]
+pub(crate) fn cycles(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
+    for weak_edge in graph.weak_edges() {
+        if let Some(line) = describe(&weak_edge, matches.is_present("all")) {
+            println!("{}", line);
+        }
+    }
+    Ok(())
+}
+
+// Describes `weak_edge` with path it conflicts with. Weak edges not conflicting are described only if `all`.
+fn describe(weak_edge: &WeakEdge, all: bool) -> Option<String> {
+    let edge = &weak_edge.edge;
+    let path = weak_edge.path.join(" -> ");
+    match weak_edge.reason {
+        WeakEdgeReason::SelfLoop | WeakEdgeReason::ClosedChain => Some(format!(
+            "{}: {} -> {} [label={:?}]",
+            weak_edge.reason, path, edge.child, edge.label
+        )),
+        WeakEdgeReason::Reparent => Some(format!(
+            "{}: {} -> {} [label={:?}] conflicts with {}",
+            weak_edge.reason, edge.parent, edge.child, edge.label, path
+        )),
+        // NOTE: These are not conflicts of tree structure
+        WeakEdgeReason::Duplicate | WeakEdgeReason::Declared if all => Some(format!(
+            "{}: {} -> {} [label={:?}]",
+            weak_edge.reason, edge.parent, edge.child, edge.label
+        )),
+        WeakEdgeReason::Duplicate | WeakEdgeReason::Declared => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+    use crate::seed_tree::node_name::NodeName;
+    use crate::seed_tree::MutationGraph;
+    use crate::subcommand::libfuzzer::cycles::describe;
+
+    #[test]
+    fn test_cycles() {
+        let edge = |parent: &str, child: &str, label: &str| MutationGraphEdge {
+            parent: NodeName::from(parent),
+            child: NodeName::from(child),
+            label: label.to_string(),
+        };
+        let mut graph = MutationGraph::new();
+        // Tree edges: d -> a -> b -> c
+        // Weak edges: a -> b (duplicate), b -> b (self-loop), c -> a (closed chain),
+        //             d -> c (re-parent), e -> f (declared)
+        graph.add_edge(&edge("a", "b", "CMP-"));
+        graph.add_edge(&edge("b", "c", "CopyPart-"));
+        graph.add_edge(&edge("a", "b", "CMP-"));
+        graph.add_edge(&edge("c", "a", "CrossOver-"));
+        graph.add_edge(&edge("b", "b", "ChangeBit-"));
+        graph.add_edge(&edge("d", "a", "EraseBytes-"));
+        graph.add_edge(&edge("d", "c", "InsertByte-"));
+        graph.add_weak_edge(&edge("e", "f", "ShuffleBytes-"));
+
+        let describe_all = |all: bool| -> Vec<String> {
+            graph
+                .weak_edges()
+                .iter()
+                .filter_map(|weak_edge| describe(weak_edge, all))
+                .collect()
+        };
+        assert_eq!(
+            describe_all(false),
+            vec![
+                "self-loop: b -> b [label=\"ChangeBit-\"]",
+                "closed-chain: a -> b -> c -> a [label=\"CrossOver-\"]",
+                "re-parent: d -> c [label=\"InsertByte-\"] conflicts with b -> c",
+            ]
+        );
+        assert_eq!(
+            describe_all(true),
+            vec![
+                "duplicate: a -> b [label=\"CMP-\"]",
+                "self-loop: b -> b [label=\"ChangeBit-\"]",
+                "closed-chain: a -> b -> c -> a [label=\"CrossOver-\"]",
+                "re-parent: d -> c [label=\"InsertByte-\"] conflicts with b -> c",
+                "declared: e -> f [label=\"ShuffleBytes-\"]",
+            ]
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use crate::seed_tree::parser::diagnostic::DiagnosticKind;
+use crate::seed_tree::parser::libfuzzer::parse_libfuzzer_mutation_graph_file_lenient;
+use crate::subcommand::result::Result;
+use clap::ArgMatches;
+use std::path::Path;
+
+#[allow(unused)]
+pub(crate) fn lint(matches: &ArgMatches, mutation_graph_file: &Path) -> Result<()> {
+    let (graph, diagnostics) = parse_libfuzzer_mutation_graph_file_lenient(mutation_graph_file)?;
+
+    if !matches.is_present("summary") {
+        for diagnostic in diagnostics.iter() {
+            println!("{}:{}", mutation_graph_file.display(), diagnostic);
+        }
+    }
+
+    let errors = diagnostics.iter().filter(|v| v.kind.is_error()).count();
+    println!(
+        "{}: {} nodes, {} edges, {} errors, {} warnings",
+        mutation_graph_file.display(),
+        graph.node_count(),
+        graph.edges().count(),
+        errors,
+        diagnostics.len() - errors
+    );
+    for name in DiagnosticKind::NAMES {
+        let count = diagnostics
+            .iter()
+            .filter(|v| v.kind.name() == *name)
+            .count();
+        if count > 0 {
+            println!("  {:<16} {}", name, count);
+        }
+    }
+    Ok(())
+}
@@ -1,5 +1,3 @@
-pub(crate) mod deriv;
-pub(crate) mod ls;
-pub(crate) mod origin;
-pub(crate) mod plot;
-pub(crate) mod pred;
+pub(crate) mod cycles;
+pub(crate) mod lint;
+pub(crate) mod stats;
@@ -0,0 +1,144 @@
+use crate::seed_tree::mutation_graph_edge::MutationGraphEdge;
+use crate::seed_tree::parser::libfuzzer::events::MutationGraphEvent;
+use crate::seed_tree::parser::libfuzzer::libfuzzer_mutation_graph_file_events;
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::result::Result;
+use clap::ArgMatches;
+use std::collections::HashMap;
+use std::path::Path;
+
+// Counts of nodes, edges and their mutators. Shared by `stats` of mutation graph files and of loaded seed trees (e.g. in shell).
+// Nodes are node declarations of files, but nodes of seed trees include ones only referred by edges.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Counts {
+    nodes: usize,
+    edges: usize,
+    self_loops: usize,
+    mutators: HashMap<String, usize>,
+}
+
+impl Counts {
+    // NOTE: Weak edges are counted too, since they are edges in input
+    fn of_graph(graph: &MutationGraph) -> Self {
+        let mut counts = Self {
+            nodes: graph.node_count(),
+            ..Default::default()
+        };
+        for edge in graph.edges() {
+            counts.count_edge(&edge);
+        }
+        for weak_edge in graph.weak_edges() {
+            counts.count_edge(&weak_edge.edge);
+        }
+        counts
+    }
+
+    fn count_edge(&mut self, edge: &MutationGraphEdge) {
+        self.edges += 1;
+        if edge.parent == edge.child {
+            self.self_loops += 1;
+        }
+        // NOTE: Label is sequence of mutators joined with '-' (e.g. "CrossOver-CMP-")
+        for mutator in edge.label.split('-').filter(|v| !v.is_empty()) {
+            *self.mutators.entry(mutator.to_string()).or_insert(0) += 1;
+        }
+    }
+
+    // Lists `top` most used mutators first, then by name
+    fn top_mutators(&self, top: usize) -> Vec<(&String, usize)> {
+        let mut mutators: Vec<(&String, usize)> =
+            self.mutators.iter().map(|(k, v)| (k, *v)).collect();
+        mutators.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        mutators.truncate(top);
+        mutators
+    }
+
+    // Prints counts naming `nodes` by what they count (e.g. "node declarations")
+    fn print(&self, nodes: &str, top: usize) {
+        println!("{:<19}{}", format!("{}:", nodes), self.nodes);
+        println!("edges:             {}", self.edges);
+        println!("self-loops:        {}", self.self_loops);
+        println!("mutators:");
+        for (mutator, count) in self.top_mutators(top) {
+            println!("  {:<20} {}", mutator, count);
+        }
+    }
+}
+
+fn top_of(matches: &ArgMatches) -> Result<usize> {
+    match matches.value_of("TOP") {
+        Some(top) => top
+            .parse::<usize>()
+            .map_err(|_| SubcommandError::InvalidArgument("TOP", top.to_string())),
+        None => Ok(10),
+    }
+}
+
+// NOTE: Statistics are computed from events of the file, so memory usage is bounded by number of mutators
+pub(crate) fn stats(matches: &ArgMatches, mutation_graph_file: &Path) -> Result<()> {
+    let top = top_of(matches)?;
+
+    let mut lines = 0;
+    let mut skipped = 0;
+    let mut counts = Counts::default();
+    let events = libfuzzer_mutation_graph_file_events(mutation_graph_file)?;
+    for event in events {
+        let (line, event) = event.map_err(|why| why.in_file(mutation_graph_file))?;
+        lines = line;
+        match event {
+            MutationGraphEvent::Node { .. } => counts.nodes += 1,
+            MutationGraphEvent::Edge { edge, .. } => counts.count_edge(&edge),
+            MutationGraphEvent::UnknownLine { .. } | MutationGraphEvent::Truncated { .. } => {
+                skipped += 1
+            }
+        }
+    }
+
+    println!("lines:             {}", lines);
+    println!("skipped lines:     {}", skipped);
+    counts.print("node declarations", top);
+    Ok(())
+}
+
+/// Same as `stats()`, but counts nodes and edges of loaded `graph`.
+/// Nodes are printed as "nodes" since they include implicit ones and exclude duplicate declarations.
+pub(crate) fn stats_of_graph(matches: &ArgMatches, graph: &MutationGraph) -> Result<()> {
+    Counts::of_graph(graph).print("nodes", top_of(matches)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::parser::libfuzzer::events::MutationGraphEvent;
+    use crate::seed_tree::parser::libfuzzer::{
+        libfuzzer_mutation_graph_file_events, parse_libfuzzer_mutation_graph_files,
+    };
+    use crate::subcommand::libfuzzer::stats::Counts;
+
+    const GRAPH_1: &str = "test/sample/mutation_graph_file/graph1.dot";
+
+    #[test]
+    fn test_stats_of_graph() {
+        // Counts of loaded seed tree agree with ones of streamed events if every node is declared exactly once
+        let mut streamed = Counts::default();
+        for event in libfuzzer_mutation_graph_file_events(GRAPH_1).unwrap() {
+            match event.unwrap().1 {
+                MutationGraphEvent::Node { .. } => streamed.nodes += 1,
+                MutationGraphEvent::Edge { edge, .. } => streamed.count_edge(&edge),
+                _ => (),
+            }
+        }
+        let graph = parse_libfuzzer_mutation_graph_files(&[GRAPH_1]).unwrap();
+        let counts = Counts::of_graph(&graph);
+        assert_eq!(counts, streamed);
+        assert_eq!((counts.nodes, counts.edges, counts.self_loops), (7, 7, 0));
+        assert_eq!(
+            counts.top_mutators(2),
+            vec![
+                (&"ChangeByte".to_string(), 4),
+                (&"ShuffleBytes".to_string(), 3)
+            ]
+        );
+    }
+}
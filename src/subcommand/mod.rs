@@ -1,7 +1,7 @@
-#[cfg(feature = "afl")]
 pub(crate) mod afl;
-#[cfg(feature = "libfuzzer")]
 pub(crate) mod libfuzzer;
 
 pub(crate) mod common;
+pub(crate) mod error;
+pub(crate) mod result;
 pub(crate) mod util;
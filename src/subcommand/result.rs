@@ -0,0 +1,3 @@
+use super::error::SubcommandError;
+
+pub(crate) type Result<T> = std::result::Result<T, SubcommandError>;
@@ -0,0 +1,27 @@
+use crate::seed_tree::corpus::CorpusIndex;
+use crate::seed_tree::hash_cache::FileHashCache;
+use crate::seed_tree::parser::error::ParseError;
+use crate::seed_tree::MutationGraph;
+use std::time::Instant;
+
+/// Indexes files in `corpus_dirs` given by `--corpus` and links nodes of `graph` to them
+#[allow(unused)]
+pub(crate) fn link_corpus(
+    corpus_dirs: &[&str],
+    graph: &mut MutationGraph,
+    cache: &mut FileHashCache,
+    jobs: usize,
+) -> Result<(), ParseError> {
+    let started = Instant::now();
+    let index = CorpusIndex::scan(corpus_dirs, cache, jobs)?;
+    let linked = index.link(graph);
+    log::info!(
+        "Indexed {} files ({} distinct) in {:?}; linked {} of {} nodes",
+        index.scanned(),
+        index.len(),
+        started.elapsed(),
+        linked,
+        graph.node_count()
+    );
+    Ok(())
+}
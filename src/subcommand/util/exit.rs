@@ -0,0 +1,18 @@
+use std::fmt::Display;
+use std::path::Path;
+use std::process;
+
+/// Prints error with `context` and exits with non-zero status
+pub(crate) fn exit_with_error<E: Display>(context: &str, why: E) -> ! {
+    eprintln!("[!] {}: {}", context, why);
+    process::exit(1)
+}
+
+/// Returns `path` as it is if exists, otherwise exits with non-zero status
+#[allow(unused)]
+pub(crate) fn require_path_exists<P: AsRef<Path>>(path: P) -> P {
+    if !path.as_ref().exists() {
+        exit_with_error("Path does not exist", path.as_ref().display());
+    }
+    path
+}
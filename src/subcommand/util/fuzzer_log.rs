@@ -0,0 +1,41 @@
+use crate::seed_tree::parser::error::ParseError;
+use crate::seed_tree::parser::libfuzzer::artifact::parse_libfuzzer_log_file;
+use crate::seed_tree::parser::libfuzzer::status::{
+    align_status_lines, parse_libfuzzer_status_log_file,
+};
+use crate::seed_tree::MutationGraph;
+
+/// Applies libFuzzer's logs given by `--log` to `graph`.
+/// Nodes discovered at `NEW` lines get their stats, and reported artifacts are inserted.
+#[allow(unused)]
+pub(crate) fn apply_libfuzzer_logs(
+    log_files: &[&str],
+    graph: &mut MutationGraph,
+) -> Result<(), ParseError> {
+    for log_file in log_files.iter() {
+        // NOTE: Stats are aligned before artifacts are inserted, as artifacts have no NEW line
+        let status_lines = parse_libfuzzer_status_log_file(log_file)?;
+        let aligned = align_status_lines(&status_lines, graph);
+        log::info!(
+            "Aligned {} of {} status lines in {} with nodes",
+            aligned,
+            status_lines.len(),
+            log_file
+        );
+
+        let artifacts = parse_libfuzzer_log_file(log_file)?;
+        for artifact in artifacts.iter() {
+            if artifact.base_unit.is_none() {
+                log::warn!(
+                    "{}:{}: base unit of {} is unknown",
+                    log_file,
+                    artifact.line,
+                    artifact.name
+                );
+            }
+            artifact.add_to(graph);
+        }
+        log::info!("Attached {} artifacts in {}", artifacts.len(), log_file);
+    }
+    Ok(())
+}
@@ -0,0 +1,11 @@
+use crate::seed_tree::hash_algorithm::HashAlgorithm;
+use clap::ArgMatches;
+
+/// Reads `--hash-algorithm` option. Default is SHA-1.
+pub(crate) fn hash_algorithm_of(matches: &ArgMatches) -> HashAlgorithm {
+    match matches.value_of("HASH_ALGORITHM") {
+        // NOTE: Value is validated by clap with `HashAlgorithm::NAMES`
+        Some(name) => HashAlgorithm::from_name(name).expect("Unknown hash algorithm"),
+        None => HashAlgorithm::default(),
+    }
+}
@@ -1 +1,8 @@
+pub mod corpus;
+pub mod exit;
+pub mod fuzzer_log;
+pub mod hash_algorithm;
 pub mod plot_dot_graph;
+pub mod query;
+pub mod seed_file;
+pub mod sort_order;
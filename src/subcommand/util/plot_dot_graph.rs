@@ -1,8 +1,14 @@
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::result::Result;
 use std::io::Write;
 use std::path::Path;
 use std::process::{Command, Stdio};
 
-pub(crate) fn plot_dot_graph(dot_graph_text: &String, format: &'static str, original_file: &Path) {
+pub(crate) fn plot_dot_graph(
+    dot_graph_text: &String,
+    format: &'static str,
+    original_file: &Path,
+) -> Result<()> {
     let path_to_render = original_file.with_extension(format);
     let mut child = Command::new("dot") // Use `dot` layout engine
         .arg(format!("-T{}", format))
@@ -11,19 +17,28 @@ pub(crate) fn plot_dot_graph(dot_graph_text: &String, format: &'static str, orig
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .spawn()
-        .expect("failed to spawn \"dot\" (graphviz)");
+        .map_err(|why| SubcommandError::CommandFailed("dot", why))?;
 
     {
-        let mut stdin = child.stdin.take().expect("Failed to open stdin");
+        let mut stdin = child.stdin.take().expect("Stdin is piped");
         stdin
             .write_all(dot_graph_text.as_bytes())
-            .expect("Failed to write to stdin");
+            .map_err(|why| SubcommandError::CommandFailed("dot", why))?;
         // Drop `stdin` to close stdin
     }
 
-    let _ = child.wait_with_output().expect("Failed to read stdout");
+    let output = child
+        .wait_with_output()
+        .map_err(|why| SubcommandError::CommandFailed("dot", why))?;
+    if !output.status.success() {
+        return Err(SubcommandError::CommandFailed(
+            "dot",
+            std::io::Error::new(std::io::ErrorKind::Other, output.status.to_string()),
+        ));
+    }
     log::info!(
         "Rendered seed tree to file \"{}\"",
         path_to_render.display()
     );
+    Ok(())
 }
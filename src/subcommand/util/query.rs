@@ -0,0 +1,21 @@
+use crate::seed_tree::query::error::QueryError;
+use crate::seed_tree::query::Query;
+use crate::seed_tree::MutationGraph;
+use clap::ArgMatches;
+
+/// Reads global `--query` option and narrows `graph` to subgraph of selected nodes.
+/// `graph` is returned as it is if no query is given.
+pub(crate) fn apply_query(
+    matches: &ArgMatches,
+    graph: MutationGraph,
+) -> Result<MutationGraph, QueryError> {
+    match matches.value_of("QUERY") {
+        Some(query) => {
+            let query = Query::parse(query)?;
+            log::info!("query = {:?}", query);
+            let selected = query.select(&graph)?;
+            Ok(graph.subgraph(&selected))
+        }
+        None => Ok(graph),
+    }
+}
@@ -0,0 +1,128 @@
+use crate::seed_tree::node_name::NodeName;
+use crate::seed_tree::MutationGraph;
+use crate::subcommand::error::SubcommandError;
+use crate::subcommand::result::Result;
+use binary_diff::BinaryDiff;
+use clap::ArgMatches;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SeedFile {
+    pub(crate) name: NodeName,
+    pub(crate) path: PathBuf,
+}
+
+/// Reads directory of seed files given by `SEEDS_DIR`, `--diff` or `--exists`
+pub(crate) fn seeds_dir_of<'a>(matches: &'a ArgMatches) -> Option<&'a Path> {
+    ["SEEDS_DIR", "SEEDS_DIR_TO_DIFF", "SEEDS_DIR_TO_EXISTS"]
+        .iter()
+        .filter_map(|name| matches.value_of(name))
+        .map(Path::new)
+        .next()
+}
+
+/// Locates seed file of node `name`.
+/// File path recorded in the node (e.g. AFL's input file) precedes `seeds_dir.join(name)` (e.g. libFuzzer's corpus).
+pub(crate) fn seed_file_of(
+    graph: &MutationGraph,
+    name: &NodeName,
+    seeds_dir: Option<&Path>,
+) -> Option<PathBuf> {
+    if let Some(node) = graph.get_node(name) {
+        if !node.file.as_os_str().is_empty() && node.file.is_file() {
+            return Some(node.file.clone());
+        }
+    }
+    match seeds_dir {
+        Some(seeds_dir) if seeds_dir.join(name).is_file() => Some(seeds_dir.join(name)),
+        _ => None,
+    }
+}
+
+/// Locates seed files of `names` keeping their order. Nodes whose seed file is not found are skipped.
+pub(crate) fn seed_files_of(
+    graph: &MutationGraph,
+    names: &[&NodeName],
+    seeds_dir: Option<&Path>,
+) -> Vec<SeedFile> {
+    names
+        .iter()
+        .filter_map(|name| {
+            let path = seed_file_of(graph, name, seeds_dir);
+            if path.is_none() {
+                log::trace!("Seed file of {} is not found", name);
+            }
+            path.map(|path| SeedFile {
+                name: (*name).clone(),
+                path,
+            })
+        })
+        .collect()
+}
+
+/// Opens seed file at `path`
+pub(crate) fn open_seed_file(path: &Path) -> Result<File> {
+    File::open(path).map_err(|why| SubcommandError::from(why).in_file(path))
+}
+
+/// Diffs seed file `seed_1` (original) and `seed_2` (patched)
+pub(crate) fn diff_seed_files(seed_1: &SeedFile, seed_2: &SeedFile) -> Result<BinaryDiff> {
+    let file_1 = open_seed_file(&seed_1.path)?;
+    let file_2 = open_seed_file(&seed_2.path)?;
+    BinaryDiff::new(&mut BufReader::new(file_1), &mut BufReader::new(file_2)).map_err(|why| {
+        SubcommandError::BinaryDiffError(format!("{} -> {}: {:?}", seed_1.name, seed_2.name, why))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::seed_tree::file_hash::FileHash;
+    use crate::seed_tree::mutation_graph_node::MutationGraphNode;
+    use crate::seed_tree::node_name::NodeName;
+    use crate::seed_tree::MutationGraph;
+    use crate::subcommand::util::seed_file::{seed_file_of, seed_files_of, SeedFile};
+    use std::path::Path;
+
+    #[test]
+    fn test_seed_file_of() {
+        let seeds_dir = Path::new("test/sample/seeds/fuzzer-test-suite-openssl-1.0.1f/");
+        let in_seeds_dir = NodeName::from("0dafd00a785bd3d2cb36722c29f0dd23497833b0");
+        let with_file = NodeName::from("000000");
+        let without_file = NodeName::from("no_such_seed");
+
+        let mut graph = MutationGraph::new();
+        graph.add_node(&MutationGraphNode::new(&in_seeds_dir));
+        graph.add_node(&MutationGraphNode::new_with_metadata(
+            &with_file,
+            false,
+            &seeds_dir.join("ff5fbe1e41fd8101c12337ff5eb5a664b2197823"),
+            &FileHash::default(),
+        ));
+        graph.add_node(&MutationGraphNode::new(&without_file));
+
+        assert_eq!(
+            seed_file_of(&graph, &with_file, None),
+            Some(seeds_dir.join("ff5fbe1e41fd8101c12337ff5eb5a664b2197823"))
+        );
+        assert_eq!(seed_file_of(&graph, &in_seeds_dir, None), None);
+        assert_eq!(
+            seed_files_of(
+                &graph,
+                &[&in_seeds_dir, &without_file, &with_file],
+                Some(seeds_dir)
+            ),
+            vec![
+                SeedFile {
+                    name: in_seeds_dir.clone(),
+                    path: seeds_dir.join(&in_seeds_dir)
+                },
+                SeedFile {
+                    name: with_file.clone(),
+                    path: seeds_dir.join("ff5fbe1e41fd8101c12337ff5eb5a664b2197823")
+                },
+            ]
+        );
+    }
+}
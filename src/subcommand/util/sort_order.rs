@@ -0,0 +1,11 @@
+use crate::seed_tree::sort_order::SortOrder;
+use clap::ArgMatches;
+
+/// Reads global `--sort` option. Default is order of node name.
+pub(crate) fn sort_order_of(matches: &ArgMatches) -> SortOrder {
+    match matches.value_of("SORT") {
+        // NOTE: Value is validated by clap with `SortOrder::NAMES`
+        Some(name) => SortOrder::from_name(name).expect("Unknown sort order"),
+        None => SortOrder::default(),
+    }
+}